@@ -0,0 +1,256 @@
+//! Command-line tool for converting raw numeric files with saturating casts.
+//!
+//! Run `satcast --help` for usage.
+
+use std::{
+    env,
+    fmt::Display,
+    fs::File,
+    io::{self, BufReader, Read, Write},
+    process::ExitCode,
+};
+
+use saturating_cast::{Endian, RawElement, SaturationElement, SaturationStats, StreamConverter};
+
+const USAGE: &str = "\
+Convert raw numeric files between primitive types with saturating casts.
+
+Usage: satcast [OPTIONS] <FROM> <TO> [INPUT] [OUTPUT]
+
+Arguments:
+  <FROM>    Type of the elements in the input
+  <TO>      Type of the elements in the output
+  [INPUT]   Input file, or `-` for stdin [default: -]
+  [OUTPUT]  Output file, or `-` for stdout [default: -]
+
+Types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+  (usize and isize have the width of the current platform)
+
+Options:
+  -e, --endian <ENDIAN>         Byte order of the input and output
+      --input-endian <ENDIAN>   Byte order of the input [default: little]
+      --output-endian <ENDIAN>  Byte order of the output [default: little]
+      --offset <BYTES>          Number of bytes to skip at the start of the input
+      --stride <BYTES>          Distance in bytes between the starts of consecutive
+                                input elements [default: size of <FROM>]
+  -r, --report                  Print a clipping report to stderr
+  -h, --help                    Print help
+
+Endianness: little, big, native";
+
+const TYPES: [&str; 14] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64",
+];
+
+struct Options {
+    from: String,
+    to: String,
+    input: Option<String>,
+    output: Option<String>,
+    converter: StreamConverter,
+    offset: u64,
+    stride: Option<u64>,
+    report: bool,
+}
+
+fn parse_endian(value: &str) -> Result<Endian, String> {
+    match value {
+        "little" | "le" => Ok(Endian::Little),
+        "big" | "be" => Ok(Endian::Big),
+        "native" => Ok(Endian::NATIVE),
+        _ => Err(format!("invalid endianness `{value}`")),
+    }
+}
+
+fn parse_bytes(value: &str) -> Result<u64, String> {
+    value.parse().map_err(|_| format!("invalid number of bytes `{value}`"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut converter = StreamConverter::new();
+    let mut offset = 0;
+    let mut stride = None;
+    let mut report = false;
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("missing value for {name}"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-e" | "--endian" => {
+                let endian = parse_endian(&value(&arg)?)?;
+                converter = converter.input_endian(endian).output_endian(endian);
+            }
+            "--input-endian" => converter = converter.input_endian(parse_endian(&value(&arg)?)?),
+            "--output-endian" => converter = converter.output_endian(parse_endian(&value(&arg)?)?),
+            "--offset" => offset = parse_bytes(&value(&arg)?)?,
+            "--stride" => stride = Some(parse_bytes(&value(&arg)?)?),
+            "-r" | "--report" => report = true,
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{arg}`"))
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let (Some(from), Some(to)) = (positional.next(), positional.next()) else {
+        return Err("expected <FROM> and <TO> types".into());
+    };
+    if let Some(ty) = [&from, &to].into_iter().find(|ty| !TYPES.contains(&ty.as_str())) {
+        return Err(format!("unknown type `{ty}`"));
+    }
+    let input = positional.next().filter(|path| path != "-");
+    let output = positional.next().filter(|path| path != "-");
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument `{extra}`"));
+    }
+
+    Ok(Some(Options { from, to, input, output, converter, offset, stride, report }))
+}
+
+// Reader which yields the first `size` bytes of every `stride` bytes of the
+// inner reader.
+struct Strided<R> {
+    inner: R,
+    size: u64,
+    stride: u64,
+    position: u64,
+}
+
+impl<R: Read> Read for Strided<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.size {
+            let gap = self.stride - self.size;
+            if io::copy(&mut self.inner.by_ref().take(gap), &mut io::sink())? < gap {
+                return Ok(0);
+            }
+            self.position = 0;
+        }
+
+        let len = buf.len().min((self.size - self.position) as usize);
+        let read = self.inner.read(&mut buf[..len])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+fn convert<S, T>(options: &Options, reader: impl Read, writer: impl Write) -> io::Result<()>
+where
+    S: RawElement + SaturationElement<T> + PartialOrd + Display,
+    T: RawElement,
+{
+    let size = S::SIZE as u64;
+    let stride = options.stride.unwrap_or(size);
+    if stride < size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("stride of {stride} bytes is smaller than the {size} byte element"),
+        ));
+    }
+
+    let stats = if stride == size {
+        options.converter.convert_stats::<S, T>(reader, writer)?
+    } else {
+        let reader = Strided { inner: reader, size, stride, position: 0 };
+        options.converter.convert_stats::<S, T>(reader, writer)?
+    };
+
+    if options.report {
+        print_report(&stats, &options.to);
+    }
+    Ok(())
+}
+
+fn print_report<S: PartialOrd + Copy + Display>(stats: &SaturationStats<S>, target: &str) {
+    let percent = |count: usize| {
+        if stats.count() == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / stats.count() as f64
+        }
+    };
+    let show = |value: Option<S>| value.map_or_else(|| "none".into(), |value| value.to_string());
+
+    eprintln!("elements:      {}", stats.count());
+    eprintln!(
+        "clipped low:   {} ({:.3}%) to {target}::MIN",
+        stats.clamped_to_min(),
+        percent(stats.clamped_to_min())
+    );
+    eprintln!(
+        "clipped high:  {} ({:.3}%) to {target}::MAX",
+        stats.clamped_to_max(),
+        percent(stats.clamped_to_max())
+    );
+    eprintln!("NaN to zero:   {} ({:.3}%)", stats.nan(), percent(stats.nan()));
+    eprintln!("source min:    {}", show(stats.min()));
+    eprintln!("source max:    {}", show(stats.max()));
+}
+
+// Expands to a `match` over every pair of source and target type names, calling
+// `convert` with the matching types.
+macro_rules! dispatch {
+    ($options: expr, $reader: expr, $writer: expr, [$($src: ty),*], $targets: tt) => {
+        match $options.from.as_str() {
+            $(stringify!($src) => dispatch!(@target $src, $options, $reader, $writer, $targets),)*
+            from => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unknown type `{from}`"))),
+        }
+    };
+    (@target $src: ty, $options: expr, $reader: expr, $writer: expr, [$($target: ty),*]) => {
+        match $options.to.as_str() {
+            $(stringify!($target) => convert::<$src, $target>($options, $reader, $writer),)*
+            to => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unknown type `{to}`"))),
+        }
+    };
+}
+
+fn run(options: &Options) -> io::Result<()> {
+    let mut reader: Box<dyn Read> = match &options.input {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    };
+    let writer: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+
+    let skipped = io::copy(&mut reader.by_ref().take(options.offset), &mut io::sink())?;
+    if skipped < options.offset {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("offset of {} bytes is past the end of the input", options.offset),
+        ));
+    }
+
+    dispatch!(
+        options,
+        reader,
+        writer,
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64],
+        [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64]
+    )
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("satcast: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("satcast: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
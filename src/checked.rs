@@ -0,0 +1,280 @@
+//! Module for trait implementations of checked, overflowing, wrapping, and
+//! strict casts.
+
+use crate::{Bound, CastError, Saturation, SaturationElement};
+
+/// Trait that enables checked casts between a source and target type. The cast
+/// returns `None` if the target type cannot represent the source value.
+///
+/// ```
+/// use saturating_cast::CheckedCast;
+///
+/// assert_eq!(Some(255_u8), 255_i32.checked_cast::<u8>());
+/// assert_eq!(None, 256_i32.checked_cast::<u8>());
+/// assert_eq!(None, (-1_i32).checked_cast::<u8>());
+/// ```
+pub trait CheckedCast {
+    /// Performs a checked cast to the target type `T`.
+    #[inline]
+    fn checked_cast<T>(self) -> Option<T>
+    where
+        Self: OverflowingElement<T>,
+    {
+        match OverflowingElement::as_overflowing_element(self) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Performs a checked cast to the target type `T`, returning a
+    /// [`CastError`] if the source value is out of range, is NaN cast to an
+    /// integer, or can't be represented exactly by `T`.
+    ///
+    /// Casts involving floats are checked by casting the result back to the
+    /// source type, so fractional parts and rounding to the nearest float are
+    /// errors. NaN and infinities cast between floats are exact.
+    ///
+    /// ```
+    /// use saturating_cast::{Bound, CheckedCast};
+    ///
+    /// assert_eq!(Ok(255_u8), 255_i32.try_cast::<u8>());
+    ///
+    /// let err = (-1_i32).try_cast::<u8>().unwrap_err();
+    /// assert_eq!(Some(Bound::Min), err.bound());
+    /// assert_eq!("value -1 is below u8::MIN", err.to_string());
+    ///
+    /// assert_eq!(Ok(255_u8), 255.0_f32.try_cast::<u8>());
+    /// assert!(255.5_f32.try_cast::<u8>().unwrap_err().is_inexact());
+    /// assert!(f32::NAN.try_cast::<u8>().unwrap_err().is_nan());
+    /// ```
+    #[inline]
+    fn try_cast<T>(self) -> Result<T, CastError<Self>>
+    where
+        Self: SaturationElement<T> + PartialEq + Copy,
+        T: SaturationElement<Self> + Copy,
+    {
+        let (value, saturation) = SaturationElement::as_saturation_element(self);
+        match saturation {
+            Saturation::Exact => {
+                // Casting back detects rounding, as the result either differs
+                // from the source or was clamped, such as `u64::MAX` rounding
+                // up to `2^64` as an `f32`
+                let (back, back_saturation) = value.as_saturation_element();
+                if back_saturation == Saturation::Exact
+                    && (back == self || (is_unordered(&back) && is_unordered(&self)))
+                {
+                    Ok(value)
+                } else {
+                    Err(CastError::inexact::<T>(self))
+                }
+            }
+            Saturation::ClampedToMin => Err(CastError::new::<T>(self, Bound::Min)),
+            Saturation::ClampedToMax => Err(CastError::new::<T>(self, Bound::Max)),
+            Saturation::Nan => Err(CastError::nan::<T>(self)),
+        }
+    }
+}
+
+// Returns `true` for values which are unequal to themselves, such as NaN.
+#[allow(clippy::eq_op)]
+#[inline]
+fn is_unordered<S: PartialEq>(value: &S) -> bool {
+    value != value
+}
+
+/// Trait that enables overflowing casts between a source and target type. The
+/// cast returns the wrapped value along with a `bool` indicating whether the
+/// target type could not represent the source value.
+///
+/// ```
+/// use saturating_cast::OverflowingCast;
+///
+/// assert_eq!((255_u8, false), 255_i32.overflowing_cast::<u8>());
+/// assert_eq!((0_u8, true), 256_i32.overflowing_cast::<u8>());
+/// assert_eq!((255_u8, true), (-1_i32).overflowing_cast::<u8>());
+/// ```
+pub trait OverflowingCast {
+    /// Performs an overflowing cast to the target type `T`.
+    #[inline]
+    fn overflowing_cast<T>(self) -> (T, bool)
+    where
+        Self: OverflowingElement<T>,
+    {
+        OverflowingElement::as_overflowing_element(self)
+    }
+}
+
+/// Trait that enables wrapping casts between a source and target type. The
+/// source value wraps around at the boundary of the target type, which is the
+/// same as casting with `as`.
+///
+/// ```
+/// use saturating_cast::WrappingCast;
+///
+/// assert_eq!(255_u8, 255_i32.wrapping_cast::<u8>());
+/// assert_eq!(0_u8, 256_i32.wrapping_cast::<u8>());
+/// assert_eq!(255_u8, (-1_i32).wrapping_cast::<u8>());
+/// ```
+pub trait WrappingCast {
+    /// Performs a wrapping cast to the target type `T`.
+    #[inline]
+    fn wrapping_cast<T>(self) -> T
+    where
+        Self: OverflowingElement<T>,
+    {
+        OverflowingElement::as_overflowing_element(self).0
+    }
+}
+
+/// Trait that enables strict casts between a source and target type. The cast
+/// panics if the target type cannot represent the source value.
+///
+/// ```should_panic
+/// use saturating_cast::StrictCast;
+///
+/// assert_eq!(255_u8, 255_i32.strict_cast::<u8>());
+/// let _ = 256_i32.strict_cast::<u8>();
+/// ```
+pub trait StrictCast {
+    /// Performs a strict cast to the target type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source value is out of range for `T`.
+    #[inline]
+    #[track_caller]
+    fn strict_cast<T>(self) -> T
+    where
+        Self: OverflowingElement<T>,
+    {
+        match OverflowingElement::as_overflowing_element(self) {
+            (value, false) => value,
+            (_, true) => panic!("attempt to cast with overflow"),
+        }
+    }
+}
+
+impl<S> CheckedCast for S {}
+impl<S> OverflowingCast for S {}
+impl<S> WrappingCast for S {}
+impl<S> StrictCast for S {}
+
+/// Supporting trait for [`CheckedCast`], [`OverflowingCast`], [`WrappingCast`],
+/// and [`StrictCast`] which performs wrapping conversion from a source element
+/// type to a target element type while reporting overflow.
+///
+/// This trait is implemented between all integer primitives. It isn't
+/// implemented for `f32` and `f64` because casting floats with `as` saturates
+/// instead of wrapping and rounds instead of failing, so the `std` naming of the
+/// cast traits wouldn't describe their behavior.
+///
+/// ```
+/// use saturating_cast::{CheckedCast, OverflowingElement};
+///
+/// #[derive(Clone, Copy)]
+/// struct Int(i32);
+///
+/// struct Uint(u8);
+///
+/// impl OverflowingElement<Uint> for Int {
+///     fn as_overflowing_element(self) -> (Uint, bool) {
+///         (Uint(self.0 as u8), u8::try_from(self.0).is_err())
+///     }
+/// }
+///
+/// assert!(Int(i32::MIN).checked_cast::<Uint>().is_none());
+/// assert_eq!(u8::MAX, Int(255).checked_cast::<Uint>().unwrap().0);
+/// ```
+pub trait OverflowingElement<T>: Sized {
+    /// Cast `self` to the target type `T`, wrapping around at the boundary of
+    /// `T`. Returns a `bool` indicating whether the source value was out of
+    /// range for `T`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_overflowing_element(self) -> (T, bool);
+}
+
+// Casting between integers with `as` wraps, while `TryFrom` fails exactly when
+// the value is out of range.
+macro_rules! impl_overflowing_casts {
+    ($src: ty => $($target: ty),*) => {$(
+        impl OverflowingElement<$target> for $src {
+            #[inline]
+            fn as_overflowing_element(self) -> ($target, bool) {
+                (self as $target, <$target>::try_from(self).is_err())
+            }
+        }
+    )*};
+}
+
+impl_overflowing_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl_overflowing_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_overflowing_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod casts {
+    use crate::{CheckedCast, OverflowingCast, StrictCast, WrappingCast};
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let _: Option<$target> = <$src>::MIN.checked_cast::<$target>();
+            let _: Result<$target, _> = <$src>::MIN.try_cast::<$target>();
+            let _: ($target, bool) = <$src>::MIN.overflowing_cast::<$target>();
+            let _: $target = <$src>::MIN.wrapping_cast::<$target>();
+            let _: $target = (0 as $src).strict_cast::<$target>();
+        )*};
+    }
+
+    macro_rules! impl_test_float_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let _: Result<$target, _> = <$src>::MIN.try_cast::<$target>();
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_float_casts!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_float_casts!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_float_casts!(u8 => f32, f64);
+        impl_test_float_casts!(u16 => f32, f64);
+        impl_test_float_casts!(u32 => f32, f64);
+        impl_test_float_casts!(u64 => f32, f64);
+        impl_test_float_casts!(u128 => f32, f64);
+        impl_test_float_casts!(usize => f32, f64);
+        impl_test_float_casts!(i8 => f32, f64);
+        impl_test_float_casts!(i16 => f32, f64);
+        impl_test_float_casts!(i32 => f32, f64);
+        impl_test_float_casts!(i64 => f32, f64);
+        impl_test_float_casts!(i128 => f32, f64);
+        impl_test_float_casts!(isize => f32, f64);
+    }
+}
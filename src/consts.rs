@@ -0,0 +1,179 @@
+//! Saturating casts between integer primitives as `const fn`s.
+//!
+//! Trait methods can't be called in `const` contexts, so this module provides a
+//! function for every pair of integer primitives, named `<source>_to_<target>`.
+//! Each function returns the same value as
+//! [`saturating_cast`][crate::SaturatingCast::saturating_cast].
+//!
+//! ```
+//! use saturating_cast::consts;
+//!
+//! const LEVEL: u8 = consts::i32_to_u8(1024);
+//! assert_eq!(255, LEVEL);
+//!
+//! // Lookup table computed at compile time
+//! const TABLE: [i8; 4] = {
+//!     let mut table = [0; 4];
+//!     let mut i = 0;
+//!     while i < table.len() {
+//!         table[i] = consts::i32_to_i8((i as i32 - 2) * 100);
+//!         i += 1;
+//!     }
+//!     table
+//! };
+//! assert_eq!([-128, -100, 0, 100], TABLE);
+//! ```
+
+// Unsigned sources are widened to `u128`, which represents every unsigned
+// value and the maximum of every target type.
+macro_rules! impl_const_uint {
+    ($src: ty => $($name: ident: $target: ty),*) => {$(
+        #[doc = concat!("Saturating cast from `", stringify!($src), "` to `", stringify!($target), "`.")]
+        #[inline]
+        pub const fn $name(x: $src) -> $target {
+            if x as u128 > <$target>::MAX as u128 {
+                <$target>::MAX
+            } else {
+                x as $target
+            }
+        }
+    )*};
+}
+
+// Negative signed sources clamp to zero, while non-negative values are widened
+// to `u128` like unsigned sources.
+macro_rules! impl_const_int_to_uint {
+    ($src: ty => $($name: ident: $target: ty),*) => {$(
+        #[doc = concat!("Saturating cast from `", stringify!($src), "` to `", stringify!($target), "`.")]
+        #[inline]
+        pub const fn $name(x: $src) -> $target {
+            if x < 0 {
+                0
+            } else if x as u128 > <$target>::MAX as u128 {
+                <$target>::MAX
+            } else {
+                x as $target
+            }
+        }
+    )*};
+}
+
+// Signed sources are widened to `i128`, which represents every signed value
+// and the bounds of every signed target type.
+macro_rules! impl_const_int_to_int {
+    ($src: ty => $($name: ident: $target: ty),*) => {$(
+        #[doc = concat!("Saturating cast from `", stringify!($src), "` to `", stringify!($target), "`.")]
+        #[inline]
+        pub const fn $name(x: $src) -> $target {
+            if (x as i128) < <$target>::MIN as i128 {
+                <$target>::MIN
+            } else if x as i128 > <$target>::MAX as i128 {
+                <$target>::MAX
+            } else {
+                x as $target
+            }
+        }
+    )*};
+}
+
+// u8
+impl_const_uint!(u8 =>
+    u8_to_u8: u8, u8_to_u16: u16, u8_to_u32: u32, u8_to_u64: u64, u8_to_u128: u128,
+    u8_to_usize: usize, u8_to_i8: i8, u8_to_i16: i16, u8_to_i32: i32, u8_to_i64: i64,
+    u8_to_i128: i128, u8_to_isize: isize
+);
+
+// u16
+impl_const_uint!(u16 =>
+    u16_to_u8: u8, u16_to_u16: u16, u16_to_u32: u32, u16_to_u64: u64, u16_to_u128: u128,
+    u16_to_usize: usize, u16_to_i8: i8, u16_to_i16: i16, u16_to_i32: i32, u16_to_i64: i64,
+    u16_to_i128: i128, u16_to_isize: isize
+);
+
+// u32
+impl_const_uint!(u32 =>
+    u32_to_u8: u8, u32_to_u16: u16, u32_to_u32: u32, u32_to_u64: u64, u32_to_u128: u128,
+    u32_to_usize: usize, u32_to_i8: i8, u32_to_i16: i16, u32_to_i32: i32, u32_to_i64: i64,
+    u32_to_i128: i128, u32_to_isize: isize
+);
+
+// u64
+impl_const_uint!(u64 =>
+    u64_to_u8: u8, u64_to_u16: u16, u64_to_u32: u32, u64_to_u64: u64, u64_to_u128: u128,
+    u64_to_usize: usize, u64_to_i8: i8, u64_to_i16: i16, u64_to_i32: i32, u64_to_i64: i64,
+    u64_to_i128: i128, u64_to_isize: isize
+);
+
+// u128
+impl_const_uint!(u128 =>
+    u128_to_u8: u8, u128_to_u16: u16, u128_to_u32: u32, u128_to_u64: u64, u128_to_u128: u128,
+    u128_to_usize: usize, u128_to_i8: i8, u128_to_i16: i16, u128_to_i32: i32, u128_to_i64: i64,
+    u128_to_i128: i128, u128_to_isize: isize
+);
+
+// usize
+impl_const_uint!(usize =>
+    usize_to_u8: u8, usize_to_u16: u16, usize_to_u32: u32, usize_to_u64: u64, usize_to_u128: u128,
+    usize_to_usize: usize, usize_to_i8: i8, usize_to_i16: i16, usize_to_i32: i32, usize_to_i64: i64,
+    usize_to_i128: i128, usize_to_isize: isize
+);
+
+// i8
+impl_const_int_to_uint!(i8 =>
+    i8_to_u8: u8, i8_to_u16: u16, i8_to_u32: u32, i8_to_u64: u64, i8_to_u128: u128,
+    i8_to_usize: usize
+);
+impl_const_int_to_int!(i8 =>
+    i8_to_i8: i8, i8_to_i16: i16, i8_to_i32: i32, i8_to_i64: i64, i8_to_i128: i128,
+    i8_to_isize: isize
+);
+
+// i16
+impl_const_int_to_uint!(i16 =>
+    i16_to_u8: u8, i16_to_u16: u16, i16_to_u32: u32, i16_to_u64: u64, i16_to_u128: u128,
+    i16_to_usize: usize
+);
+impl_const_int_to_int!(i16 =>
+    i16_to_i8: i8, i16_to_i16: i16, i16_to_i32: i32, i16_to_i64: i64, i16_to_i128: i128,
+    i16_to_isize: isize
+);
+
+// i32
+impl_const_int_to_uint!(i32 =>
+    i32_to_u8: u8, i32_to_u16: u16, i32_to_u32: u32, i32_to_u64: u64, i32_to_u128: u128,
+    i32_to_usize: usize
+);
+impl_const_int_to_int!(i32 =>
+    i32_to_i8: i8, i32_to_i16: i16, i32_to_i32: i32, i32_to_i64: i64, i32_to_i128: i128,
+    i32_to_isize: isize
+);
+
+// i64
+impl_const_int_to_uint!(i64 =>
+    i64_to_u8: u8, i64_to_u16: u16, i64_to_u32: u32, i64_to_u64: u64, i64_to_u128: u128,
+    i64_to_usize: usize
+);
+impl_const_int_to_int!(i64 =>
+    i64_to_i8: i8, i64_to_i16: i16, i64_to_i32: i32, i64_to_i64: i64, i64_to_i128: i128,
+    i64_to_isize: isize
+);
+
+// i128
+impl_const_int_to_uint!(i128 =>
+    i128_to_u8: u8, i128_to_u16: u16, i128_to_u32: u32, i128_to_u64: u64, i128_to_u128: u128,
+    i128_to_usize: usize
+);
+impl_const_int_to_int!(i128 =>
+    i128_to_i8: i8, i128_to_i16: i16, i128_to_i32: i32, i128_to_i64: i64, i128_to_i128: i128,
+    i128_to_isize: isize
+);
+
+// isize
+impl_const_int_to_uint!(isize =>
+    isize_to_u8: u8, isize_to_u16: u16, isize_to_u32: u32, isize_to_u64: u64, isize_to_u128: u128,
+    isize_to_usize: usize
+);
+impl_const_int_to_int!(isize =>
+    isize_to_i8: i8, isize_to_i16: i16, isize_to_i32: i32, isize_to_i64: i64, isize_to_i128: i128,
+    isize_to_isize: isize
+);
@@ -0,0 +1,191 @@
+//! Module for errors describing out of range casts and mismatched slices.
+
+use core::fmt;
+
+/// Bound of the target type which was violated by a cast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bound {
+    /// The source value was less than the target minimum.
+    Min,
+    /// The source value was greater than the target maximum.
+    Max,
+}
+
+/// Error describing a source value which is out of range for the target type,
+/// NaN cast to an integer, or a value which the target type can't represent
+/// exactly.
+///
+/// The error records the source value, the name of the target type, and the
+/// [`Bound`] of the target type which was violated, if any. It is returned by
+/// [`try_cast`][crate::CheckedCast::try_cast] and can be created for any pair of
+/// source and target types with [`CastError::new`], [`CastError::nan`], or
+/// [`CastError::inexact`].
+///
+/// ```
+/// use saturating_cast::{Bound, CastError, CheckedCast};
+///
+/// let err = 70000_u32.try_cast::<u16>().unwrap_err();
+/// assert_eq!(70000, *err.value());
+/// assert_eq!("u16", err.target());
+/// assert_eq!(Some(Bound::Max), err.bound());
+/// assert_eq!("value 70000 exceeds u16::MAX", err.to_string());
+///
+/// let err = CastError::new::<u8>(-1_i32, Bound::Min);
+/// assert_eq!("value -1 is below u8::MIN", err.to_string());
+///
+/// let err = f32::NAN.try_cast::<u8>().unwrap_err();
+/// assert!(err.is_nan());
+/// assert_eq!("cannot cast NaN to u8", err.to_string());
+///
+/// let err = 2.5_f32.try_cast::<u8>().unwrap_err();
+/// assert!(err.is_inexact());
+/// assert_eq!("value 2.5 cannot be represented exactly as u8", err.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CastError<S> {
+    value: S,
+    target: &'static str,
+    reason: Reason,
+}
+
+// Reason that the source value couldn't be cast to the target type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Reason {
+    Bound(Bound),
+    Nan,
+    Inexact,
+}
+
+impl<S> CastError<S> {
+    /// Create an error for a source `value` which violates the `bound` of the
+    /// target type `T`.
+    #[inline]
+    pub fn new<T>(value: S, bound: Bound) -> Self {
+        Self { value, target: core::any::type_name::<T>(), reason: Reason::Bound(bound) }
+    }
+
+    /// Create an error for a NaN source `value` cast to the target type `T`,
+    /// which has no bound that NaN violates.
+    #[inline]
+    pub fn nan<T>(value: S) -> Self {
+        Self { value, target: core::any::type_name::<T>(), reason: Reason::Nan }
+    }
+
+    /// Create an error for a source `value` which is within the range of the
+    /// target type `T` but would be rounded, such as a float with a fractional
+    /// part cast to an integer.
+    #[inline]
+    pub fn inexact<T>(value: S) -> Self {
+        Self { value, target: core::any::type_name::<T>(), reason: Reason::Inexact }
+    }
+
+    /// Returns a reference to the source value.
+    #[inline]
+    pub fn value(&self) -> &S {
+        &self.value
+    }
+
+    /// Consumes the error, returning the source value.
+    #[inline]
+    pub fn into_value(self) -> S {
+        self.value
+    }
+
+    /// Returns the name of the target type.
+    #[inline]
+    pub fn target(&self) -> &'static str {
+        self.target
+    }
+
+    /// Returns the bound of the target type which was violated, or `None` if
+    /// the source value was NaN or inexact.
+    #[inline]
+    pub fn bound(&self) -> Option<Bound> {
+        match self.reason {
+            Reason::Bound(bound) => Some(bound),
+            Reason::Nan | Reason::Inexact => None,
+        }
+    }
+
+    /// Returns `true` if the source value was NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.reason == Reason::Nan
+    }
+
+    /// Returns `true` if the source value was within range but can't be
+    /// represented exactly by the target type.
+    #[inline]
+    pub fn is_inexact(&self) -> bool {
+        self.reason == Reason::Inexact
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for CastError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            Reason::Bound(Bound::Min) => {
+                write!(f, "value {} is below {}::MIN", self.value, self.target)
+            }
+            Reason::Bound(Bound::Max) => {
+                write!(f, "value {} exceeds {}::MAX", self.value, self.target)
+            }
+            Reason::Nan => write!(f, "cannot cast NaN to {}", self.target),
+            Reason::Inexact => {
+                write!(f, "value {} cannot be represented exactly as {}", self.value, self.target)
+            }
+        }
+    }
+}
+
+impl<S: fmt::Debug + fmt::Display> core::error::Error for CastError<S> {}
+
+/// Error returned when the source and destination slices of a bulk cast have
+/// different lengths.
+///
+/// ```
+/// use saturating_cast::saturating_cast_slice;
+///
+/// let mut dst = [0_u8; 2];
+/// let err = saturating_cast_slice(&[1_i32, 2, 3], &mut dst).unwrap_err();
+/// assert_eq!(3, err.src_len());
+/// assert_eq!(2, err.dst_len());
+/// assert_eq!("source length 3 does not match destination length 2", err.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthError {
+    src_len: usize,
+    dst_len: usize,
+}
+
+impl LengthError {
+    /// Create an error for source and destination slices of the given lengths.
+    #[inline]
+    pub const fn new(src_len: usize, dst_len: usize) -> Self {
+        Self { src_len, dst_len }
+    }
+
+    /// Returns the length of the source slice.
+    #[inline]
+    pub const fn src_len(&self) -> usize {
+        self.src_len
+    }
+
+    /// Returns the length of the destination slice.
+    #[inline]
+    pub const fn dst_len(&self) -> usize {
+        self.dst_len
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "source length {} does not match destination length {}",
+            self.src_len, self.dst_len
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
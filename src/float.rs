@@ -0,0 +1,356 @@
+//! Module for trait implementations of saturating casts to and from floating
+//! point primitives.
+
+use crate::{SaturatingElement, Saturation, SaturationElement};
+
+/// Supporting trait for [`SaturatingCast`][crate::SaturatingCast] methods
+/// which let the caller choose how NaN is handled when casting from a floating
+/// point type.
+///
+/// [`SaturatingElement::as_element`] converts NaN to zero. Implementors of this
+/// trait instead report NaN as `None` so that the NaN can be replaced with a
+/// caller-supplied value or returned as an error.
+///
+/// ```
+/// use saturating_cast::SaturatingCast;
+///
+/// assert_eq!(0_i16, f32::NAN.saturating_cast::<i16>());
+/// assert_eq!(-1_i16, f32::NAN.saturating_cast_or(-1_i16));
+/// assert!(f32::NAN.try_saturating_cast::<i16>().is_err());
+///
+/// assert_eq!(Ok(i16::MAX), f32::INFINITY.try_saturating_cast::<i16>());
+/// ```
+pub trait SaturatingFloatElement<T>: SaturatingElement<T> {
+    /// Returns `None` if `self` is NaN, otherwise returns `self` saturated to
+    /// the target type `T`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_non_nan_element(self) -> Option<T>;
+}
+
+/// Error returned from
+/// [`try_saturating_cast`][crate::SaturatingCast::try_saturating_cast] when the
+/// source value is NaN.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NanError;
+
+impl core::fmt::Display for NanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("cannot saturate NaN to an integer")
+    }
+}
+
+impl core::error::Error for NanError {}
+
+// Float to integer conversions. Casting with `as` rounds toward zero, saturates
+// at the bounds of the target type (including infinities), and converts NaN to
+// zero.
+macro_rules! impl_float_to_int {
+    ($src: ty => $($target: ty),*) => {$(
+        impl SaturatingElement<$target> for $src {
+            #[inline]
+            fn as_element(self) -> $target {
+                self as $target
+            }
+        }
+
+        impl SaturatingFloatElement<$target> for $src {
+            #[inline]
+            fn as_non_nan_element(self) -> Option<$target> {
+                if self.is_nan() {
+                    None
+                } else {
+                    Some(self as $target)
+                }
+            }
+        }
+
+        // NaN is reported separately from clamping since zero isn't a bound.
+        // Other values are clamped if they're out of range after rounding
+        // toward zero.
+        // The target minimum is zero or a negative power of two, and one past
+        // the target maximum is a power of two, so both are exact as floats. If
+        // one less than the minimum isn't representable, every float below the
+        // minimum is at least one less than it. One past `u128::MAX` is
+        // infinity as an `f32`.
+        impl SaturationElement<$target> for $src {
+            #[inline]
+            fn as_saturation_element(self) -> ($target, Saturation) {
+                let min = <$target>::MIN as $src;
+                let max_bound = (<$target>::MAX / 2 + 1) as $src * 2.0;
+                let saturation = if self.is_nan() {
+                    Saturation::Nan
+                } else if self < min && self <= min - 1.0 {
+                    Saturation::ClampedToMin
+                } else if self >= max_bound {
+                    Saturation::ClampedToMax
+                } else {
+                    Saturation::Exact
+                };
+                (self as $target, saturation)
+            }
+        }
+    )*};
+}
+
+// f32
+impl_float_to_int!(f32 => u8, u16, u32, u64, u128, usize);
+impl_float_to_int!(f32 => i8, i16, i32, i64, i128, isize);
+
+// f64
+impl_float_to_int!(f64 => u8, u16, u32, u64, u128, usize);
+impl_float_to_int!(f64 => i8, i16, i32, i64, i128, isize);
+
+// Integer to float conversions. Casting with `as` rounds to the nearest
+// representable value, with ties rounding to even. The only integers larger
+// than `f32::MAX` are `u128` values, so `u128` values are clamped before casting
+// to `f32` to avoid rounding to infinity.
+macro_rules! impl_int_to_float {
+    ($src: ty => $($target: ty),*) => {$(
+        impl SaturatingElement<$target> for $src {
+            #[inline]
+            fn as_element(self) -> $target {
+                self as $target
+            }
+        }
+
+        impl SaturationElement<$target> for $src {
+            #[inline]
+            fn as_saturation_element(self) -> ($target, Saturation) {
+                (self as $target, Saturation::Exact)
+            }
+        }
+    )*};
+}
+
+impl_int_to_float!(u8 => f32, f64);
+impl_int_to_float!(u16 => f32, f64);
+impl_int_to_float!(u32 => f32, f64);
+impl_int_to_float!(u64 => f32, f64);
+impl_int_to_float!(u128 => f64);
+impl_int_to_float!(usize => f32, f64);
+
+impl_int_to_float!(i8 => f32, f64);
+impl_int_to_float!(i16 => f32, f64);
+impl_int_to_float!(i32 => f32, f64);
+impl_int_to_float!(i64 => f32, f64);
+impl_int_to_float!(i128 => f32, f64);
+impl_int_to_float!(isize => f32, f64);
+
+impl SaturatingElement<f32> for u128 {
+    #[inline]
+    fn as_element(self) -> f32 {
+        // `f32::MAX` is an integer that is exactly representable in `u128`
+        self.min(f32::MAX as u128) as f32
+    }
+}
+
+impl SaturationElement<f32> for u128 {
+    #[inline]
+    fn as_saturation_element(self) -> (f32, Saturation) {
+        let saturation =
+            if self > f32::MAX as u128 { Saturation::ClampedToMax } else { Saturation::Exact };
+        (self.as_element(), saturation)
+    }
+}
+
+// Float to float conversions
+impl SaturatingElement<f32> for f32 {
+    #[inline]
+    fn as_element(self) -> f32 {
+        self
+    }
+}
+
+impl SaturatingElement<f64> for f32 {
+    #[inline]
+    fn as_element(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl SaturatingElement<f64> for f64 {
+    #[inline]
+    fn as_element(self) -> f64 {
+        self
+    }
+}
+
+impl SaturationElement<f32> for f32 {
+    #[inline]
+    fn as_saturation_element(self) -> (f32, Saturation) {
+        (self, Saturation::Exact)
+    }
+}
+
+impl SaturationElement<f64> for f32 {
+    #[inline]
+    fn as_saturation_element(self) -> (f64, Saturation) {
+        (f64::from(self), Saturation::Exact)
+    }
+}
+
+impl SaturationElement<f64> for f64 {
+    #[inline]
+    fn as_saturation_element(self) -> (f64, Saturation) {
+        (self, Saturation::Exact)
+    }
+}
+
+// Finite values larger in magnitude than `f32::MAX` would round to infinity, so
+// they are clamped to `f32::MIN` or `f32::MAX` instead. Infinities and NaN are
+// representable in `f32` and are passed through.
+impl SaturatingElement<f32> for f64 {
+    #[inline]
+    fn as_element(self) -> f32 {
+        let value = self as f32;
+        if value.is_infinite() && self.is_finite() {
+            if self > 0.0 {
+                f32::MAX
+            } else {
+                f32::MIN
+            }
+        } else {
+            value
+        }
+    }
+}
+
+impl SaturationElement<f32> for f64 {
+    #[inline]
+    fn as_saturation_element(self) -> (f32, Saturation) {
+        let saturation = if self < f64::from(f32::MIN) && self.is_finite() {
+            Saturation::ClampedToMin
+        } else if self > f64::from(f32::MAX) && self.is_finite() {
+            Saturation::ClampedToMax
+        } else {
+            Saturation::Exact
+        };
+        (self.as_element(), saturation)
+    }
+}
+
+/// Options for saturating an `f64` to an `f32` with [`F32Narrowing::narrow`].
+///
+/// With the default options, `narrow` is the same as the saturating cast from
+/// `f64` to `f32`: finite values are clamped to `f32::MIN..=f32::MAX`, while
+/// infinities, NaN, signed zeros, and subnormal results are passed through.
+///
+/// ```
+/// use saturating_cast::{F32Narrowing, SaturatingCast};
+///
+/// assert_eq!(f32::MAX, 1e300_f64.saturating_cast::<f32>());
+/// assert_eq!(f32::MAX, F32Narrowing::new().narrow(1e300));
+///
+/// let narrowing = F32Narrowing::new()
+///     .nan_to_zero(true)
+///     .unsigned_zero(true)
+///     .flush_subnormals(true);
+/// assert_eq!(0.0, narrowing.narrow(f64::NAN));
+/// assert!(narrowing.narrow(-0.0).is_sign_positive());
+/// assert_eq!(0.0, narrowing.narrow(1e-40));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct F32Narrowing {
+    nan_to_zero: bool,
+    unsigned_zero: bool,
+    flush_subnormals: bool,
+}
+
+impl F32Narrowing {
+    /// Create narrowing options which pass through NaN, keep the sign of zero,
+    /// and keep subnormal results.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { nan_to_zero: false, unsigned_zero: false, flush_subnormals: false }
+    }
+
+    /// Convert NaN to positive zero instead of passing it through.
+    #[inline]
+    pub const fn nan_to_zero(mut self, nan_to_zero: bool) -> Self {
+        self.nan_to_zero = nan_to_zero;
+        self
+    }
+
+    /// Convert negative zero to positive zero instead of keeping the sign.
+    #[inline]
+    pub const fn unsigned_zero(mut self, unsigned_zero: bool) -> Self {
+        self.unsigned_zero = unsigned_zero;
+        self
+    }
+
+    /// Convert results which are subnormal in `f32` to zero of the same sign.
+    #[inline]
+    pub const fn flush_subnormals(mut self, flush_subnormals: bool) -> Self {
+        self.flush_subnormals = flush_subnormals;
+        self
+    }
+
+    /// Saturate `value` to an `f32` according to the narrowing options.
+    #[inline]
+    pub fn narrow(self, value: f64) -> f32 {
+        let mut narrowed: f32 = value.as_element();
+        if self.nan_to_zero && narrowed.is_nan() {
+            narrowed = 0.0;
+        }
+        if self.flush_subnormals && narrowed.is_subnormal() {
+            narrowed = if narrowed.is_sign_negative() { -0.0 } else { 0.0 };
+        }
+        if self.unsigned_zero && narrowed == 0.0 {
+            narrowed = 0.0;
+        }
+        narrowed
+    }
+}
+
+#[cfg(test)]
+mod casts {
+    use crate::SaturatingCast;
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let _: $target = <$src>::MIN.saturating_cast::<$target>();
+            let _: $target = <$src>::NAN.saturating_cast_or::<$target>(0);
+            let _ = <$src>::NAN.try_saturating_cast::<$target>();
+            let _: ($target, _) = <$src>::MIN.saturating_cast_outcome::<$target>();
+        )*};
+    }
+
+    macro_rules! impl_test_int_casts {
+        ($($src: ty),*) => {$(
+            let _: f32 = <$src>::MIN.saturating_cast::<f32>();
+            let _: f64 = <$src>::MIN.saturating_cast::<f64>();
+            let _: (f32, _) = <$src>::MIN.saturating_cast_outcome::<f32>();
+            let _: (f64, _) = <$src>::MIN.saturating_cast_outcome::<f64>();
+        )*};
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_int_casts!(u8, u16, u32, u64, u128, usize);
+        impl_test_int_casts!(i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        let _: f32 = f32::MIN.saturating_cast::<f32>();
+        let _: f64 = f32::MIN.saturating_cast::<f64>();
+        let _: f32 = f64::MIN.saturating_cast::<f32>();
+        let _: f64 = f64::MIN.saturating_cast::<f64>();
+        let _: (f32, _) = f32::MIN.saturating_cast_outcome::<f32>();
+        let _: (f64, _) = f32::MIN.saturating_cast_outcome::<f64>();
+        let _: (f32, _) = f64::MIN.saturating_cast_outcome::<f32>();
+        let _: (f64, _) = f64::MIN.saturating_cast_outcome::<f64>();
+    }
+
+    #[test]
+    fn f32_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, u128, usize);
+        impl_test_all_casts!(f32 => i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn f64_all_casts() {
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, u128, usize);
+        impl_test_all_casts!(f64 => i8, i16, i32, i64, i128, isize);
+    }
+}
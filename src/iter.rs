@@ -0,0 +1,321 @@
+//! Module for saturating casts over iterators.
+
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+use crate::{SaturatingElement, SaturationElement, SaturationStats};
+
+/// Extension trait for iterators which performs a saturating cast on every item.
+///
+/// This trait is implemented for all iterators. The adapter is named
+/// `saturating_cast_each` because
+/// [`SaturatingCast`][crate::SaturatingCast] is implemented for all types,
+/// including iterators, so both traits can be imported together.
+///
+/// ```
+/// use saturating_cast::SaturatingCastIterator;
+///
+/// let values = [-1_i32, 0, 128, 1024];
+/// let bytes: Vec<u8> = values.into_iter().saturating_cast_each::<u8>().collect();
+/// assert_eq!(vec![0, 0, 128, 255], bytes);
+///
+/// let reversed: Vec<i8> = values.iter().saturating_cast_each::<i8>().rev().collect();
+/// assert_eq!(vec![127, 127, 0, -1], reversed);
+/// ```
+pub trait SaturatingCastIterator: Iterator + Sized {
+    /// Creates an iterator which performs a saturating cast of every item to the
+    /// target type `T`.
+    #[inline]
+    fn saturating_cast_each<T>(self) -> SaturatingCastIter<Self, T>
+    where
+        Self::Item: SaturatingElement<T>,
+    {
+        SaturatingCastIter { iter: self, target: PhantomData }
+    }
+
+    /// Creates an iterator which performs a saturating cast of every item to the
+    /// target type `T`, collecting [`SaturationStats`] for the items it yields.
+    ///
+    /// Iterators over references can be adapted with `copied` first, so that
+    /// the statistics record source values.
+    ///
+    /// ```
+    /// use saturating_cast::SaturatingCastIterator;
+    ///
+    /// let values = [-1_i32, 0, 128, 1024];
+    /// let mut iter = values.iter().copied().saturating_cast_stats::<u8>();
+    /// let bytes: Vec<u8> = iter.by_ref().collect();
+    /// assert_eq!(vec![0, 0, 128, 255], bytes);
+    ///
+    /// let stats = iter.stats();
+    /// assert_eq!((1, 1), (stats.clamped_to_min(), stats.clamped_to_max()));
+    /// assert_eq!((Some(-1), Some(1024)), (stats.min(), stats.max()));
+    /// ```
+    #[inline]
+    fn saturating_cast_stats<T>(self) -> SaturatingCastStatsIter<Self, T>
+    where
+        Self::Item: SaturationElement<T> + PartialOrd + Copy,
+    {
+        SaturatingCastStatsIter { iter: self, stats: SaturationStats::new(), target: PhantomData }
+    }
+}
+
+impl<I: Iterator> SaturatingCastIterator for I {}
+
+/// Iterator which performs a saturating cast of every item of the inner
+/// iterator to the target type `T`.
+///
+/// This struct is created by
+/// [`SaturatingCastIterator::saturating_cast_each`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SaturatingCastIter<I, T> {
+    iter: I,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<I, T> SaturatingCastIter<I, T> {
+    /// Consumes the adapter, returning the inner iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Clone, T> Clone for SaturatingCastIter<I, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { iter: self.iter.clone(), target: PhantomData }
+    }
+}
+
+impl<I: fmt::Debug, T> fmt::Debug for SaturatingCastIter<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaturatingCastIter").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, T> Iterator for SaturatingCastIter<I, T>
+where
+    I: Iterator,
+    I::Item: SaturatingElement<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(SaturatingElement::as_element)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.iter.nth(n).map(SaturatingElement::as_element)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.iter.fold(init, |acc, x| f(acc, x.as_element()))
+    }
+}
+
+impl<I, T> DoubleEndedIterator for SaturatingCastIter<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: SaturatingElement<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(SaturatingElement::as_element)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.iter.nth_back(n).map(SaturatingElement::as_element)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.iter.rfold(init, |acc, x| f(acc, x.as_element()))
+    }
+}
+
+impl<I, T> ExactSizeIterator for SaturatingCastIter<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: SaturatingElement<T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for SaturatingCastIter<I, T>
+where
+    I: FusedIterator,
+    I::Item: SaturatingElement<T>,
+{
+}
+
+/// Iterator which performs a saturating cast of every item of the inner
+/// iterator to the target type `T`, collecting [`SaturationStats`] for the
+/// items it yields.
+///
+/// This struct is created by
+/// [`SaturatingCastIterator::saturating_cast_stats`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SaturatingCastStatsIter<I: Iterator, T> {
+    iter: I,
+    stats: SaturationStats<I::Item>,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<I: Iterator, T> SaturatingCastStatsIter<I, T> {
+    /// Returns the statistics for the items yielded so far.
+    #[inline]
+    pub fn stats(&self) -> &SaturationStats<I::Item> {
+        &self.stats
+    }
+
+    /// Consumes the adapter, returning the statistics for the items yielded.
+    #[inline]
+    pub fn into_stats(self) -> SaturationStats<I::Item> {
+        self.stats
+    }
+
+    #[inline]
+    fn record(&mut self, value: I::Item) -> T
+    where
+        I::Item: SaturationElement<T> + PartialOrd + Copy,
+    {
+        let (element, saturation) = value.as_saturation_element();
+        self.stats.record(value, saturation);
+        element
+    }
+}
+
+impl<I: Iterator + Clone, T> Clone for SaturatingCastStatsIter<I, T>
+where
+    I::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { iter: self.iter.clone(), stats: self.stats.clone(), target: PhantomData }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, T> fmt::Debug for SaturatingCastStatsIter<I, T>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaturatingCastStatsIter")
+            .field("iter", &self.iter)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+impl<I, T> Iterator for SaturatingCastStatsIter<I, T>
+where
+    I: Iterator,
+    I::Item: SaturationElement<T> + PartialOrd + Copy,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let value = self.iter.next()?;
+        Some(self.record(value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for SaturatingCastStatsIter<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: SaturationElement<T> + PartialOrd + Copy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let value = self.iter.next_back()?;
+        Some(self.record(value))
+    }
+}
+
+impl<I, T> ExactSizeIterator for SaturatingCastStatsIter<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: SaturationElement<T> + PartialOrd + Copy,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for SaturatingCastStatsIter<I, T>
+where
+    I: FusedIterator,
+    I::Item: SaturationElement<T> + PartialOrd + Copy,
+{
+}
+
+#[cfg(test)]
+mod casts {
+    use crate::{SaturatingCast, SaturatingCastIterator};
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let src = [<$src>::MIN, <$src>::MAX];
+            let mut dst = src.into_iter().saturating_cast_each::<$target>();
+            assert_eq!(Some(<$src>::MIN.saturating_cast::<$target>()), dst.next());
+            assert_eq!(Some(<$src>::MAX.saturating_cast::<$target>()), dst.next());
+            assert_eq!(None, dst.next());
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+}
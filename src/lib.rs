@@ -1,4 +1,4 @@
-//! Saturating casts for integer primitives.
+//! Saturating casts for integer and floating point primitives.
 //!
 //! ## Features
 //!
@@ -6,6 +6,35 @@
 //! - Saturating casts implemented between all of the following:
 //!   - `u8`, `u16`, `u32`, `u64`, `u128`, `usize`
 //!   - `i8`, `i16`, `i32`, `i64`, `i128`, `isize`
+//! - Saturating casts from `f32` and `f64` to all integer primitives, with a
+//!   choice of NaN handling
+//! - Saturating casts from all integer primitives to `f32` and `f64` which never
+//!   produce infinity
+//! - Saturating casts from `f64` to `f32` which clamp finite values to
+//!   `f32::MIN..=f32::MAX`
+//! - Rounding modes for saturating casts between integers and floats
+//! - Reporting whether casts were clamped to the target minimum or maximum,
+//!   or converted NaN to zero, and the excess that was clipped off
+//! - Checked, overflowing, wrapping, and strict casts between integer primitives
+//! - Saturating casts from references, such as `&i32` and `&mut f64`
+//! - Element-wise saturating casts between fixed-size arrays and tuples of up to
+//!   12 elements
+//! - `SaturatingFrom` and `SaturatingInto` traits mirroring `From` and `Into`
+//! - `const fn` saturating casts between integer primitives in the `consts` module
+//! - Iterator adapter which performs saturating casts on every item
+//! - Bulk saturating casts from a source slice into a destination slice
+//! - Statistics of clamped casts and extreme source values for slices and
+//!   iterators
+//! - SIMD kernels for bulk narrowing casts in the `simd` module, with runtime
+//!   CPU feature detection when the `std` feature is enabled
+//! - Lane-wise saturating casts between `core::simd` vectors, with the nightly
+//!   `portable_simd` feature
+//! - Saturating casts between vectors, which reuse the allocation when the
+//!   element types have the same size and alignment, with the `alloc` feature
+//! - Streaming saturating casts of raw binary data between readers and writers,
+//!   with the `std` feature
+//! - `satcast` command-line tool for converting raw numeric files, with the `std`
+//!   feature
 //! - Saturating traits can be implemented for user types
 //!
 //! ## Description
@@ -45,17 +74,289 @@
 //! value is out of range for the target type: the resulting value will be the
 //! minimum or maximum of the target type.
 //!
+//! [`saturating_cast_outcome`][SaturatingCast::saturating_cast_outcome]
+//! reports which bound, if any, the value was clamped to, and whether NaN was
+//! converted to zero.
+//!
+//! ```
+//! use saturating_cast::{SaturatingCast, Saturation};
+//!
+//! assert_eq!((255, Saturation::ClampedToMax), 1024_i32.saturating_cast_outcome::<u8>());
+//! assert_eq!((0, Saturation::ClampedToMin), (-128_i8).saturating_cast_outcome::<u16>());
+//! assert_eq!((100, Saturation::Exact), 100_u64.saturating_cast_outcome::<i8>());
+//! assert_eq!((0, Saturation::Nan), f64::NAN.saturating_cast_outcome::<i8>());
+//! ```
+//!
+//! [`saturating_cast_excess`][SaturatingCast::saturating_cast_excess] returns
+//! the amount that was clipped off, which can be carried forward.
+//!
+//! ```
+//! use saturating_cast::SaturatingCast;
+//!
+//! assert_eq!((255, 769), 1024_i32.saturating_cast_excess::<u8>());
+//! ```
+//!
 //! <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
-//! <strong>Note:</strong> This crate only casts and returns primitive integers.
+//! <strong>Note:</strong> This crate only casts and returns primitive numbers.
 //! Saturating arithmetic is still required to avoid wraparound in release mode
 //! and panicking in debug mode (or with overflow checks on in release mode).
 //! </p>
 //!
+//! [`SaturatingFrom`] and [`SaturatingInto`] mirror `From` and `Into`, which
+//! avoids the turbofish and allows saturating conversions as generic bounds.
+//!
+//! ```
+//! use saturating_cast::{SaturatingFrom, SaturatingInto};
+//!
+//! assert_eq!(255, u8::saturating_from(1024_i32));
+//!
+//! fn to_u16(value: impl SaturatingInto<u16>) -> u16 {
+//!     value.saturating_into()
+//! }
+//! assert_eq!(0, to_u16(-128_i8));
+//! ```
+//!
+//! [`saturate`] is a free function which coerces to a function pointer.
+//!
+//! ```
+//! use saturating_cast::saturate;
+//!
+//! let to_u8: fn(i32) -> u8 = saturate;
+//! assert_eq!(255, to_u8(1024));
+//! ```
+//!
+//! Arrays are cast element-wise, so `[i32; 4]` can be saturated into `[u8; 4]`
+//! in one call. Tuples of up to 12 elements are cast position-wise, with each
+//! position using its own source and target types.
+//!
+//! ```
+//! use saturating_cast::SaturatingCast;
+//!
+//! assert_eq!([0, 100, 255, 255], [-20_i32, 100, 300, 255].saturating_cast::<[u8; 4]>());
+//!
+//! let record = (-40000_i64, 12_i64, 70000_u32);
+//! assert_eq!((i16::MIN, 12, u16::MAX), record.saturating_cast::<(i16, i16, u16)>());
+//! ```
+//!
+//! [`SaturatingCastIterator`] adds a `saturating_cast_each` adapter to iterators,
+//! which keeps the `ExactSizeIterator`, `DoubleEndedIterator`, and
+//! `FusedIterator` implementations of the inner iterator.
+//!
+//! ```
+//! use saturating_cast::SaturatingCastIterator;
+//!
+//! let values = [-1_i32, 128, 1024];
+//! let bytes: Vec<u8> = values.iter().saturating_cast_each::<u8>().collect();
+//! assert_eq!(vec![0, 128, 255], bytes);
+//! ```
+//!
+//! [`saturating_cast_slice`] casts every element of a source slice into a
+//! destination slice of the same length, in a loop which the compiler can
+//! auto-vectorize.
+//!
+//! ```
+//! use saturating_cast::saturating_cast_slice;
+//!
+//! let mut dst = [0_u8; 3];
+//! saturating_cast_slice(&[-1_i32, 128, 1024], &mut dst)?;
+//! assert_eq!([0, 128, 255], dst);
+//! # Ok::<(), saturating_cast::LengthError>(())
+//! ```
+//!
+//! [`SaturationStats`] counts the elements which were clamped to each bound and
+//! records the extreme source values. It is returned by
+//! [`saturating_cast_slice_stats`] and collected by the
+//! [`saturating_cast_stats`][SaturatingCastIterator::saturating_cast_stats]
+//! iterator adapter, which avoids a second pass over the buffer.
+//!
+//! ```
+//! use saturating_cast::saturating_cast_slice_stats;
+//!
+//! let mut dst = [0_u8; 3];
+//! let stats = saturating_cast_slice_stats(&[-1_i32, 128, 1024], &mut dst)?;
+//! assert_eq!([0, 128, 255], dst);
+//! assert_eq!(2, stats.clamped());
+//! assert_eq!(Some(1024), stats.max());
+//! # Ok::<(), saturating_cast::LengthError>(())
+//! ```
+//!
+//! The [`simd`] module provides bulk narrowing casts such as `i32` to `i16` and
+//! `u16` to `u8` which use SSE2, SSE4.1, or AVX2 instructions on `x86_64`,
+//! falling back to [`saturating_cast_slice`] on other platforms.
+//!
+//! ```
+//! use saturating_cast::simd;
+//!
+//! let mut dst = [0_u8; 3];
+//! simd::u16_to_u8(&[1, 255, 1024], &mut dst)?;
+//! assert_eq!([1, 255, 255], dst);
+//! # Ok::<(), saturating_cast::LengthError>(())
+//! ```
+//!
+//! With the nightly `portable_simd` feature enabled, `Simd<S, N>` can be cast
+//! to `Simd<T, N>` for every pair of lane types which supports saturating casts.
+//!
+//! ```
+//! # #![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+//! # #[cfg(feature = "portable_simd")]
+//! # {
+//! use core::simd::Simd;
+//! use saturating_cast::SaturatingCast;
+//!
+//! let x = Simd::from_array([-1_i32, 128, 1024, 5]);
+//! assert_eq!([0, 128, 255, 5], x.saturating_cast::<Simd<u8, 4>>().to_array());
+//! # }
+//! ```
+//!
+//! With the `alloc` feature enabled, `saturating_cast_vec` converts a `Vec<S>`
+//! into a `Vec<T>`, converting each element in place when `T` has the same
+//! size and alignment as `S`. Conversions between types with different
+//! layouts, such as `i32` to `i16`, allocate a new buffer.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use saturating_cast::saturating_cast_vec;
+//!
+//! let samples: Vec<u32> = saturating_cast_vec(vec![-1_i32, 100, 70000]);
+//! assert_eq!(vec![0, 100, 70000], samples);
+//! # }
+//! ```
+//!
+//! With the `std` feature enabled, `StreamConverter` reads raw little- or
+//! big-endian elements from an `std::io::Read` and writes them, saturated to
+//! another type, to an `std::io::Write` in constant memory.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! use saturating_cast::StreamConverter;
+//!
+//! let capture: Vec<u8> = [-40000_i32, 40000].iter().flat_map(|x| x.to_le_bytes()).collect();
+//! let mut pcm = Vec::new();
+//! StreamConverter::new().convert::<i32, i16>(capture.as_slice(), &mut pcm)?;
+//! assert_eq!([0x00, 0x80, 0xff, 0x7f], pcm.as_slice());
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! The `satcast` binary, built with the `std` feature, applies the same
+//! conversion to files or standard input and output. It supports choosing the
+//! byte order, skipping a header with `--offset`, reading interleaved data with
+//! `--stride`, and printing a clipping report with `--report`.
+//!
+//! ```text
+//! cargo install saturating_cast --features std
+//! satcast --report --offset 44 i32 i16 capture.raw pcm.raw
+//! ```
+//!
+//! ## Casting from floating point
+//!
+//! Casts from `f32` and `f64` to integers round toward zero before saturating.
+//! Infinities saturate to the target minimum or maximum. NaN is converted to
+//! `0` by [`saturating_cast`][SaturatingCast::saturating_cast], to a
+//! caller-supplied value by
+//! [`saturating_cast_or`][SaturatingCast::saturating_cast_or], or to an error
+//! by [`try_saturating_cast`][SaturatingCast::try_saturating_cast].
+//!
+//! ```
+//! use saturating_cast::{NanError, SaturatingCast};
+//!
+//! assert_eq!(-32768_i16, (-1e9_f64).saturating_cast::<i16>());
+//! assert_eq!(u8::MAX, f32::INFINITY.saturating_cast::<u8>());
+//!
+//! assert_eq!(0_i16, f32::NAN.saturating_cast::<i16>());
+//! assert_eq!(-1_i16, f32::NAN.saturating_cast_or(-1));
+//! assert_eq!(Err(NanError), f32::NAN.try_saturating_cast::<i16>());
+//! ```
+//!
+//! Other rounding modes are available with
+//! [`saturating_cast_rounded`][SaturatingCast::saturating_cast_rounded]. The
+//! value is rounded before saturating, so values which round past the target
+//! bounds still saturate.
+//!
+//! ```
+//! use saturating_cast::{Rounding, SaturatingCast};
+//!
+//! assert_eq!(3_u8, 2.5_f32.saturating_cast_rounded::<u8>(Rounding::NearestAwayFromZero));
+//! assert_eq!(255_u8, 255.5_f32.saturating_cast_rounded::<u8>(Rounding::NearestEven));
+//! assert_eq!(-3_i8, (-2.1_f64).saturating_cast_rounded::<i8>(Rounding::TowardNegative));
+//! ```
+//!
+//! Casts from integers to `f32` and `f64` round to the nearest representable
+//! value, with ties rounding to even. The rounded value may be further from
+//! zero than the source value, such as `u64::MAX` rounding up to `2^64`. The
+//! only integers larger than `f32::MAX` are `u128` values, which saturate to
+//! `f32::MAX` instead of rounding to infinity.
+//!
+//! ```
+//! use saturating_cast::SaturatingCast;
+//!
+//! assert_eq!(f32::MAX, u128::MAX.saturating_cast::<f32>());
+//! assert_eq!(18446744073709551616.0, u64::MAX.saturating_cast::<f32>());
+//! assert_eq!(16777216.0, 16777217_i32.saturating_cast::<f32>());
+//! ```
+//!
+//! [`saturating_cast_rounded`][SaturatingCast::saturating_cast_rounded] takes a
+//! [`Rounding`] mode which guarantees the side of the source value that the
+//! result falls on, such as never exceeding the source magnitude.
+//!
+//! ```
+//! use saturating_cast::{Rounding, SaturatingCast};
+//!
+//! let x = u64::MAX;
+//! let y: f32 = x.saturating_cast_rounded(Rounding::TowardZero);
+//! assert!((y as u64) < x);
+//! ```
+//!
+//! Casts from `f64` to `f32` clamp finite values to `f32::MIN..=f32::MAX`
+//! instead of rounding them to infinity. [`F32Narrowing`] provides options for
+//! converting NaN to zero, dropping the sign of zero, and flushing subnormal
+//! results to zero.
+//!
+//! ```
+//! use saturating_cast::{F32Narrowing, SaturatingCast};
+//!
+//! assert_eq!(f32::MIN, (-1e300_f64).saturating_cast::<f32>());
+//! assert_eq!(f32::INFINITY, f64::INFINITY.saturating_cast::<f32>());
+//!
+//! let narrowing = F32Narrowing::new().nan_to_zero(true);
+//! assert_eq!(0.0, narrowing.narrow(f64::NAN));
+//! ```
+//!
+//! ## Other integer casts
+//!
+//! [`CheckedCast`], [`OverflowingCast`], [`WrappingCast`], and [`StrictCast`]
+//! follow the naming of the integer methods in `std` for casts which don't
+//! saturate.
+//!
+//! ```
+//! use saturating_cast::{CheckedCast, OverflowingCast, StrictCast, WrappingCast};
+//!
+//! assert_eq!(None, 1024_i32.checked_cast::<u8>());
+//! assert_eq!((0, true), 1024_i32.overflowing_cast::<u8>());
+//! assert_eq!(0, 1024_i32.wrapping_cast::<u8>());
+//! assert_eq!(255, 255_i32.strict_cast::<u8>());
+//! ```
+//!
+//! [`try_cast`][CheckedCast::try_cast] returns a [`CastError`] which records the
+//! source value, the target type, and the violated [`Bound`], or that the source
+//! value was NaN or would be rounded.
+//!
+//! ```
+//! use saturating_cast::CheckedCast;
+//!
+//! let err = 70000_u32.try_cast::<u16>().unwrap_err();
+//! assert_eq!("value 70000 exceeds u16::MAX", err.to_string());
+//! ```
+//!
 //! ## Implementing saturating casts for custom types
 //!
-//! The following code implements the two traits needed for saturating casts
-//! from `Int` to `Uint`. The functionality for saturation is defined within
-//! [`SaturatingElement`]'s `as_element`.
+//! The following code implements the trait needed for saturating casts from
+//! `Int` to `Uint`. The functionality for saturation is defined within
+//! [`SaturatingElement`]'s `as_element`. [`SaturatingCast`] is implemented for
+//! all types, so `saturating_cast` can be called once `SaturatingElement` is
+//! implemented.
 //!
 //! ```
 //! use saturating_cast::{SaturatingCast, SaturatingElement};
@@ -71,8 +372,6 @@
 //!     }
 //! }
 //!
-//! impl SaturatingCast for Int {}
-//!
 //! assert_eq!(u8::MIN, Int(i32::MIN).saturating_cast::<Uint>().0);
 //! assert_eq!(u8::MAX, Int(512).saturating_cast::<Uint>().0);
 #![forbid(
@@ -80,10 +379,45 @@
     missing_docs,
     non_ascii_idents,
     noop_method_call,
-    unsafe_code,
     unused_results
 )]
+#![deny(unsafe_code)]
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod checked;
+pub mod consts;
+mod error;
+mod float;
+mod iter;
+#[cfg(feature = "portable_simd")]
+mod portable_simd;
+mod round;
 mod saturate;
-pub use saturate::{SaturatingCast, SaturatingElement};
+pub mod simd;
+mod slice;
+mod stats;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod vec;
+pub use checked::{CheckedCast, OverflowingCast, OverflowingElement, StrictCast, WrappingCast};
+pub use error::{Bound, CastError, LengthError};
+pub use float::{F32Narrowing, NanError, SaturatingFloatElement};
+pub use iter::{SaturatingCastIter, SaturatingCastIterator, SaturatingCastStatsIter};
+pub use round::{Rounding, SaturatingRoundElement};
+pub use saturate::{
+    saturate, SaturatingCast, SaturatingElement, SaturatingFrom, SaturatingInto, Saturation,
+    SaturationElement,
+};
+pub use slice::{saturating_cast_slice, saturating_cast_slice_stats};
+pub use stats::SaturationStats;
+#[cfg(feature = "std")]
+pub use stream::{Endian, RawElement, StreamConverter};
+#[cfg(feature = "alloc")]
+pub use vec::saturating_cast_vec;
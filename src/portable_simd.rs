@@ -0,0 +1,114 @@
+//! Module for lane-wise saturating casts between `core::simd` vectors.
+//!
+//! Each implementation matches the scalar [`SaturatingElement`] implementation
+//! for the lane types. `u128` and `i128` are not supported as lane types.
+
+use core::simd::{prelude::*, Simd};
+
+use crate::{SaturatingCast, SaturatingElement};
+
+// The bounds of the target type are saturated to the source type, so clamping
+// to them leaves only values which cast exactly to the target type.
+macro_rules! impl_simd_int_casts {
+    ($src: ty => $($target: ty),*) => {$(
+        impl<const N: usize> SaturatingElement<Simd<$target, N>> for Simd<$src, N> {
+            #[inline]
+            fn as_element(self) -> Simd<$target, N> {
+                let min = Simd::splat(<$target>::MIN.saturating_cast::<$src>());
+                let max = Simd::splat(<$target>::MAX.saturating_cast::<$src>());
+                self.simd_clamp(min, max).cast()
+            }
+        }
+    )*};
+}
+
+// Casts from floats to integers with `as` saturate and convert NaN to zero, and
+// casts from integers to floats round to nearest. Lanes can't be `u128`, so no
+// integer rounds to infinity.
+macro_rules! impl_simd_as_casts {
+    ($src: ty => $($target: ty),*) => {$(
+        impl<const N: usize> SaturatingElement<Simd<$target, N>> for Simd<$src, N> {
+            #[inline]
+            fn as_element(self) -> Simd<$target, N> {
+                self.cast()
+            }
+        }
+    )*};
+}
+
+impl_simd_int_casts!(u8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(u16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(u32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(u64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(usize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl_simd_int_casts!(i8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(i16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(i32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(i64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_simd_int_casts!(isize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl_simd_as_casts!(f32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+impl_simd_as_casts!(f64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f64);
+
+impl_simd_as_casts!(u8 => f32, f64);
+impl_simd_as_casts!(u16 => f32, f64);
+impl_simd_as_casts!(u32 => f32, f64);
+impl_simd_as_casts!(u64 => f32, f64);
+impl_simd_as_casts!(usize => f32, f64);
+
+impl_simd_as_casts!(i8 => f32, f64);
+impl_simd_as_casts!(i16 => f32, f64);
+impl_simd_as_casts!(i32 => f32, f64);
+impl_simd_as_casts!(i64 => f32, f64);
+impl_simd_as_casts!(isize => f32, f64);
+
+// Finite values are clamped to the range of `f32`, while infinities and NaN are
+// cast unchanged.
+impl<const N: usize> SaturatingElement<Simd<f32, N>> for Simd<f64, N> {
+    #[inline]
+    fn as_element(self) -> Simd<f32, N> {
+        let min = Simd::splat(f32::MIN as f64);
+        let max = Simd::splat(f32::MAX as f64);
+        self.is_finite().select(self.simd_clamp(min, max), self).cast()
+    }
+}
+
+#[cfg(test)]
+mod casts {
+    use core::simd::Simd;
+
+    use crate::SaturatingCast;
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let src = Simd::<$src, 4>::from_array([<$src>::MIN, <$src>::MAX, 0 as $src, 1 as $src]);
+            let dst: Simd<$target, 4> = src.saturating_cast();
+            assert_eq!(src.to_array().map(SaturatingCast::saturating_cast::<$target>), dst.to_array());
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+    }
+}
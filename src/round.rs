@@ -0,0 +1,237 @@
+//! Module for saturating casts with a choice of rounding mode.
+
+use crate::SaturatingElement;
+
+/// Rounding mode used by
+/// [`saturating_cast_rounded`][crate::SaturatingCast::saturating_cast_rounded]
+/// when the source value cannot be represented exactly in the target type.
+///
+/// The rounded value is always clamped to the range of the target type, so
+/// saturation takes precedence over the rounding direction.
+///
+/// ```
+/// use saturating_cast::{Rounding, SaturatingCast};
+///
+/// let x = 16777217_i32;
+/// assert_eq!(16777216.0, x.saturating_cast_rounded::<f32>(Rounding::NearestEven));
+/// assert_eq!(16777216.0, x.saturating_cast_rounded::<f32>(Rounding::TowardZero));
+/// assert_eq!(16777218.0, x.saturating_cast_rounded::<f32>(Rounding::TowardPositive));
+/// assert_eq!(16777216.0, x.saturating_cast_rounded::<f32>(Rounding::TowardNegative));
+///
+/// let y = -2.5_f32;
+/// assert_eq!(-2, y.saturating_cast_rounded::<i8>(Rounding::NearestEven));
+/// assert_eq!(-3, y.saturating_cast_rounded::<i8>(Rounding::NearestAwayFromZero));
+/// assert_eq!(-2, y.saturating_cast_rounded::<i8>(Rounding::TowardZero));
+/// assert_eq!(-2, y.saturating_cast_rounded::<i8>(Rounding::TowardPositive));
+/// assert_eq!(-3, y.saturating_cast_rounded::<i8>(Rounding::TowardNegative));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest value, with ties rounding to the value with an even
+    /// least significant digit. This is the rounding used by `as` casts from
+    /// integers to floats.
+    #[default]
+    NearestEven,
+    /// Round to the nearest value, with ties rounding away from zero. This is
+    /// the rounding used by `f32::round`.
+    NearestAwayFromZero,
+    /// Round toward zero, so the magnitude of the result never exceeds the
+    /// magnitude of the source value. This truncation is the rounding used by
+    /// `as` casts from floats to integers.
+    TowardZero,
+    /// Round toward positive infinity, so the result is never less than the
+    /// source value. This is the rounding used by `f32::ceil`.
+    TowardPositive,
+    /// Round toward negative infinity, so the result is never greater than the
+    /// source value. This is the rounding used by `f32::floor`.
+    TowardNegative,
+}
+
+/// Supporting trait for
+/// [`saturating_cast_rounded`][crate::SaturatingCast::saturating_cast_rounded]
+/// which performs saturating conversion with a choice of [`Rounding`].
+pub trait SaturatingRoundElement<T>: SaturatingElement<T> {
+    /// Round `self` to a value representable in `T` using `rounding`, then clamp
+    /// that value to within the range of `T::MIN..=T::MAX`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_rounded_element(self, rounding: Rounding) -> T;
+}
+
+// Rounds an integer with the given magnitude and sign to a float.
+//
+// Casting with `as` rounds to nearest, ties to even. The bits of the magnitude
+// which don't fit in the float's mantissa determine whether that rounding moved
+// away from zero, which is corrected by stepping to the adjacent float.
+macro_rules! impl_round_int_to_float {
+    ($name: ident, $float: ty) => {
+        fn $name(magnitude: u128, negative: bool, rounding: Rounding) -> $float {
+            let nearest = magnitude as $float;
+
+            let bits = u128::BITS - magnitude.leading_zeros();
+            let dropped = bits.saturating_sub(<$float>::MANTISSA_DIGITS);
+            let (inexact, tie, rounded_up) = if dropped == 0 {
+                (false, false, false)
+            } else {
+                let remainder = magnitude & ((1 << dropped) - 1);
+                let half = 1 << (dropped - 1);
+                let odd = (magnitude >> dropped) & 1 == 1;
+                (remainder != 0, remainder == half, remainder > half || (remainder == half && odd))
+            };
+
+            let truncated = if rounded_up { nearest.next_down() } else { nearest };
+            let away = if inexact && !rounded_up { nearest.next_up() } else { nearest };
+            let rounded = match rounding {
+                Rounding::NearestEven => nearest,
+                Rounding::NearestAwayFromZero if tie => away,
+                Rounding::NearestAwayFromZero => nearest,
+                Rounding::TowardZero => truncated,
+                Rounding::TowardPositive if negative => truncated,
+                Rounding::TowardPositive => away,
+                Rounding::TowardNegative if negative => away,
+                Rounding::TowardNegative => truncated,
+            };
+
+            let saturated = rounded.min(<$float>::MAX);
+            if negative {
+                -saturated
+            } else {
+                saturated
+            }
+        }
+    };
+}
+
+impl_round_int_to_float!(round_to_f32, f32);
+impl_round_int_to_float!(round_to_f64, f64);
+
+macro_rules! impl_uint_round_to_float {
+    ($($src: ty),*) => {$(
+        impl SaturatingRoundElement<f32> for $src {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> f32 {
+                round_to_f32(self as u128, false, rounding)
+            }
+        }
+
+        impl SaturatingRoundElement<f64> for $src {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> f64 {
+                round_to_f64(self as u128, false, rounding)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_round_to_float {
+    ($($src: ty),*) => {$(
+        impl SaturatingRoundElement<f32> for $src {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> f32 {
+                round_to_f32(self.unsigned_abs() as u128, self < 0, rounding)
+            }
+        }
+
+        impl SaturatingRoundElement<f64> for $src {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> f64 {
+                round_to_f64(self.unsigned_abs() as u128, self < 0, rounding)
+            }
+        }
+    )*};
+}
+
+impl_uint_round_to_float!(u8, u16, u32, u64, u128, usize);
+impl_int_round_to_float!(i8, i16, i32, i64, i128, isize);
+
+// Rounds a float to an integral float.
+//
+// Floats with a magnitude of at least `2^(MANTISSA_DIGITS - 1)` have no
+// fractional part. Smaller values are truncated through an integer type which
+// can represent them exactly, then the fractional part determines whether to
+// step one away from the truncated value.
+macro_rules! impl_round_float {
+    ($name: ident, $float: ty, $int: ty) => {
+        fn $name(value: $float, rounding: Rounding) -> $float {
+            let limit = (1_u64 << (<$float>::MANTISSA_DIGITS - 1)) as $float;
+            if !(value > -limit && value < limit) {
+                return value;
+            }
+
+            let truncated = value as $int;
+            let fraction = value - truncated as $float;
+            let step = if fraction < 0.0 { -1.0 } else { 1.0 };
+            let distance = fraction * step;
+            let adjust = match rounding {
+                Rounding::NearestEven => distance > 0.5 || (distance == 0.5 && truncated % 2 != 0),
+                Rounding::NearestAwayFromZero => distance >= 0.5,
+                Rounding::TowardZero => false,
+                Rounding::TowardPositive => fraction > 0.0,
+                Rounding::TowardNegative => fraction < 0.0,
+            };
+
+            if adjust {
+                truncated as $float + step
+            } else {
+                truncated as $float
+            }
+        }
+    };
+}
+
+impl_round_float!(round_f32, f32, i32);
+impl_round_float!(round_f64, f64, i64);
+
+// Casting the rounded value with `as` saturates at the bounds of the target type
+// and converts NaN to zero.
+macro_rules! impl_float_round_to_int {
+    ($($target: ty),*) => {$(
+        impl SaturatingRoundElement<$target> for f32 {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> $target {
+                round_f32(self, rounding) as $target
+            }
+        }
+
+        impl SaturatingRoundElement<$target> for f64 {
+            #[inline]
+            fn as_rounded_element(self, rounding: Rounding) -> $target {
+                round_f64(self, rounding) as $target
+            }
+        }
+    )*};
+}
+
+impl_float_round_to_int!(u8, u16, u32, u64, u128, usize);
+impl_float_round_to_int!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod casts {
+    use crate::{Rounding, SaturatingCast};
+
+    macro_rules! impl_test_all_casts {
+        ($($src: ty),*) => {$(
+            let _: f32 = <$src>::MIN.saturating_cast_rounded::<f32>(Rounding::TowardZero);
+            let _: f64 = <$src>::MIN.saturating_cast_rounded::<f64>(Rounding::TowardZero);
+        )*};
+    }
+
+    macro_rules! impl_test_float_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let _: $target = <$src>::MIN.saturating_cast_rounded::<$target>(Rounding::TowardZero);
+        )*};
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(u8, u16, u32, u64, u128, usize);
+        impl_test_all_casts!(i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_float_casts!(f32 => u8, u16, u32, u64, u128, usize);
+        impl_test_float_casts!(f32 => i8, i16, i32, i64, i128, isize);
+        impl_test_float_casts!(f64 => u8, u16, u32, u64, u128, usize);
+        impl_test_float_casts!(f64 => i8, i16, i32, i64, i128, isize);
+    }
+}
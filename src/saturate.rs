@@ -1,6 +1,8 @@
 //! Module for trait implementations of saturating casts.
-use core::{i128, i16, i32, i64, i8, isize};
-use core::{u128, u16, u32, u64, u8, usize};
+
+use core::ops::Sub;
+
+use crate::{Bound, NanError, Rounding, SaturatingFloatElement, SaturatingRoundElement};
 
 /// Trait that enables saturating casts between a source and target type. The
 /// value is not preserved if the target type cannot represent the original
@@ -35,27 +37,120 @@ pub trait SaturatingCast {
     {
         SaturatingElement::as_element(self)
     }
-}
 
-impl SaturatingCast for u8 {}
-impl SaturatingCast for u16 {}
-impl SaturatingCast for u32 {}
-impl SaturatingCast for u64 {}
-impl SaturatingCast for u128 {}
-impl SaturatingCast for usize {}
+    /// Performs a saturating cast to the target type `T`, returning the value
+    /// along with whether it was clamped to the target minimum or maximum.
+    ///
+    /// ```
+    /// use saturating_cast::{SaturatingCast, Saturation};
+    ///
+    /// let (value, saturation) = 70000_u32.saturating_cast_outcome::<u16>();
+    /// assert_eq!(u16::MAX, value);
+    /// assert_eq!(Saturation::ClampedToMax, saturation);
+    /// ```
+    #[inline]
+    fn saturating_cast_outcome<T>(self) -> (T, Saturation)
+    where
+        Self: SaturationElement<T>,
+    {
+        SaturationElement::as_saturation_element(self)
+    }
+
+    /// Performs a saturating cast to the target type `T`, returning the value
+    /// along with the excess that was clipped off by saturation.
+    ///
+    /// When the value was clamped, the excess is `self` minus the saturated
+    /// value, so it is positive when clamped to the target maximum and negative
+    /// when clamped to the target minimum. The excess always fits in the source
+    /// type because the saturated value lies between zero and `self`.
+    ///
+    /// The excess is zero when the value wasn't clamped, including casts
+    /// involving floats which truncate a fractional part or round to the
+    /// nearest float. NaN cast to an integer has an excess of NaN.
+    ///
+    /// ```
+    /// use saturating_cast::SaturatingCast;
+    ///
+    /// assert_eq!((255_u8, 769), 1024_i32.saturating_cast_excess::<u8>());
+    /// assert_eq!((-128_i8, -72), (-200_i64).saturating_cast_excess::<i8>());
+    /// assert_eq!((100_u16, 0), 100_u32.saturating_cast_excess::<u16>());
+    /// assert_eq!((16777220.0_f32, 0), 16777219_u64.saturating_cast_excess::<f32>());
+    /// ```
+    #[inline]
+    fn saturating_cast_excess<T>(self) -> (T, Self)
+    where
+        Self: SaturationElement<T> + Sub<Output = Self> + Default + Copy,
+        T: SaturatingElement<Self> + Copy,
+    {
+        let (value, saturation) = SaturationElement::as_saturation_element(self);
+        match saturation {
+            Saturation::Exact => (value, Self::default()),
+            _ => (value, self - value.as_element()),
+        }
+    }
+
+    /// Performs a saturating cast to the target type `T`, returning `nan` if
+    /// `self` is NaN.
+    ///
+    /// ```
+    /// use saturating_cast::SaturatingCast;
+    ///
+    /// assert_eq!(-1_i16, f32::NAN.saturating_cast_or(-1));
+    /// assert_eq!(i16::MIN, f64::NEG_INFINITY.saturating_cast_or(-1));
+    /// ```
+    #[inline]
+    fn saturating_cast_or<T>(self, nan: T) -> T
+    where
+        Self: SaturatingFloatElement<T>,
+    {
+        SaturatingFloatElement::as_non_nan_element(self).unwrap_or(nan)
+    }
+
+    /// Performs a saturating cast to the target type `T`, returning an error if
+    /// `self` is NaN.
+    ///
+    /// ```
+    /// use saturating_cast::{NanError, SaturatingCast};
+    ///
+    /// assert_eq!(Err(NanError), f32::NAN.try_saturating_cast::<u8>());
+    /// assert_eq!(Ok(255), 300.0_f32.try_saturating_cast::<u8>());
+    /// ```
+    #[inline]
+    fn try_saturating_cast<T>(self) -> Result<T, NanError>
+    where
+        Self: SaturatingFloatElement<T>,
+    {
+        SaturatingFloatElement::as_non_nan_element(self).ok_or(NanError)
+    }
+
+    /// Performs a saturating cast to the target type `T`, using `rounding` when
+    /// the source value is not exactly representable in `T`.
+    ///
+    /// ```
+    /// use saturating_cast::{Rounding, SaturatingCast};
+    ///
+    /// let x = u64::MAX;
+    /// assert_eq!(18446744073709551616.0, x.saturating_cast::<f32>());
+    /// assert_eq!(18446742974197923840.0, x.saturating_cast_rounded::<f32>(Rounding::TowardZero));
+    /// ```
+    #[inline]
+    fn saturating_cast_rounded<T>(self, rounding: Rounding) -> T
+    where
+        Self: SaturatingRoundElement<T>,
+    {
+        SaturatingRoundElement::as_rounded_element(self, rounding)
+    }
+}
 
-impl SaturatingCast for i8 {}
-impl SaturatingCast for i16 {}
-impl SaturatingCast for i32 {}
-impl SaturatingCast for i64 {}
-impl SaturatingCast for i128 {}
-impl SaturatingCast for isize {}
+impl<S> SaturatingCast for S {}
 
 /// Supporting trait for [`SaturatingCast`] which performs saturating conversion
 /// from a source element type to a target element type.
 ///
-/// The following code shows how to implement traits that allow for saturating
-/// casts from the custom type `Int` to another custom type, `Uint`.
+/// The following code shows how to implement the trait that allows for
+/// saturating casts from the custom type `Int` to another custom type, `Uint`.
+/// [`SaturatingCast`] is implemented for all types, so its methods are available
+/// as soon as `SaturatingElement` is implemented.
 ///
 /// ```
 /// use saturating_cast::{SaturatingCast, SaturatingElement};
@@ -71,16 +166,319 @@ impl SaturatingCast for isize {}
 ///     }
 /// }
 ///
-/// impl SaturatingCast for Int {}
-///
 /// assert_eq!(u8::MIN, Int(i32::MIN).saturating_cast::<Uint>().0);
 /// assert_eq!(u8::MAX, Int(512).saturating_cast::<Uint>().0);
-pub trait SaturatingElement<T>: Copy {
+/// ```
+///
+/// The source type does not need to be `Copy`. Types which own their data can
+/// implement the trait for the type itself to consume `self`, and for a
+/// reference to the type to borrow `self`.
+///
+/// ```
+/// use saturating_cast::{SaturatingCast, SaturatingElement};
+///
+/// // Arbitrary-precision integer stored as base 2^32 digits, least
+/// // significant first
+/// struct BigUint(Vec<u32>);
+///
+/// impl SaturatingElement<u32> for &BigUint {
+///     fn as_element(self) -> u32 {
+///         match self.0.as_slice() {
+///             [] => 0,
+///             [digit] => *digit,
+///             _ => u32::MAX,
+///         }
+///     }
+/// }
+///
+/// impl SaturatingElement<u32> for BigUint {
+///     fn as_element(self) -> u32 {
+///         (&self).as_element()
+///     }
+/// }
+///
+/// let x = BigUint(vec![7, 1]);
+/// assert_eq!(u32::MAX, (&x).saturating_cast::<u32>());
+/// assert_eq!(u32::MAX, x.saturating_cast::<u32>());
+/// assert_eq!(5, BigUint(vec![5]).saturating_cast::<u32>());
+/// ```
+pub trait SaturatingElement<T>: Sized {
     /// Clamp `self` to within the range of `T::MIN..=T::MAX`, then return that
     /// value cast to the target type `T`.
+    #[allow(clippy::wrong_self_convention)]
     fn as_element(self) -> T;
 }
 
+// References to `Copy` sources, which allows for casting while iterating over
+// borrowed elements.
+impl<S: SaturatingElement<T> + Copy, T> SaturatingElement<T> for &S {
+    #[inline]
+    fn as_element(self) -> T {
+        (*self).as_element()
+    }
+}
+
+impl<S: SaturatingElement<T> + Copy, T> SaturatingElement<T> for &mut S {
+    #[inline]
+    fn as_element(self) -> T {
+        (*self).as_element()
+    }
+}
+
+// Arrays saturate element-wise, with each element using its own
+// `SaturatingElement` implementation.
+impl<S: SaturatingElement<T>, T, const N: usize> SaturatingElement<[T; N]> for [S; N] {
+    #[inline]
+    fn as_element(self) -> [T; N] {
+        self.map(SaturatingElement::as_element)
+    }
+}
+
+// Tuples saturate position-wise, with each position using the
+// `SaturatingElement` implementation of its own source and target types.
+macro_rules! impl_saturating_tuple {
+    ($(($src: ident, $target: ident, $index: tt)),+) => {
+        impl<$($src: SaturatingElement<$target>, $target),+> SaturatingElement<($($target,)+)>
+            for ($($src,)+)
+        {
+            #[inline]
+            fn as_element(self) -> ($($target,)+) {
+                ($(self.$index.as_element(),)+)
+            }
+        }
+    };
+}
+
+impl_saturating_tuple!((S0, T0, 0));
+impl_saturating_tuple!((S0, T0, 0), (S1, T1, 1));
+impl_saturating_tuple!((S0, T0, 0), (S1, T1, 1), (S2, T2, 2));
+impl_saturating_tuple!((S0, T0, 0), (S1, T1, 1), (S2, T2, 2), (S3, T3, 3));
+impl_saturating_tuple!((S0, T0, 0), (S1, T1, 1), (S2, T2, 2), (S3, T3, 3), (S4, T4, 4));
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6),
+    (S7, T7, 7)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6),
+    (S7, T7, 7),
+    (S8, T8, 8)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6),
+    (S7, T7, 7),
+    (S8, T8, 8),
+    (S9, T9, 9)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6),
+    (S7, T7, 7),
+    (S8, T8, 8),
+    (S9, T9, 9),
+    (S10, T10, 10)
+);
+impl_saturating_tuple!(
+    (S0, T0, 0),
+    (S1, T1, 1),
+    (S2, T2, 2),
+    (S3, T3, 3),
+    (S4, T4, 4),
+    (S5, T5, 5),
+    (S6, T6, 6),
+    (S7, T7, 7),
+    (S8, T8, 8),
+    (S9, T9, 9),
+    (S10, T10, 10),
+    (S11, T11, 11)
+);
+
+/// Performs a saturating cast of `value` to the target type `T`.
+///
+/// This is the free function form of
+/// [`saturating_cast`][SaturatingCast::saturating_cast], which coerces to a
+/// function pointer and can be passed directly to iterator adapters.
+///
+/// ```
+/// use saturating_cast::saturate;
+///
+/// assert_eq!(255, saturate::<u8, _>(1024_i32));
+///
+/// let to_u8: fn(i32) -> u8 = saturate;
+/// assert_eq!(0, to_u8(-5));
+///
+/// let bytes: Vec<u8> = [-1_i16, 1, 300].into_iter().map(saturate).collect();
+/// assert_eq!(vec![0, 1, 255], bytes);
+/// ```
+#[inline]
+pub fn saturate<T, S: SaturatingElement<T>>(value: S) -> T {
+    value.as_element()
+}
+
+/// Saturating conversion from a source type `S`, mirroring [`From`].
+///
+/// This trait is implemented for every target type `T` where the source type
+/// implements [`SaturatingElement<T>`], so it can't be implemented directly.
+///
+/// ```
+/// use saturating_cast::SaturatingFrom;
+///
+/// assert_eq!(255, u8::saturating_from(1024_i32));
+/// assert_eq!(i8::MIN, i8::saturating_from(-1e9_f64));
+/// ```
+pub trait SaturatingFrom<S>: Sized {
+    /// Performs a saturating conversion from `value`.
+    fn saturating_from(value: S) -> Self;
+}
+
+impl<S: SaturatingElement<T>, T> SaturatingFrom<S> for T {
+    #[inline]
+    fn saturating_from(value: S) -> T {
+        value.as_element()
+    }
+}
+
+/// Saturating conversion into a target type `T`, mirroring [`Into`].
+///
+/// This trait is implemented for every source type where `T` implements
+/// [`SaturatingFrom`], so it can't be implemented directly. It is useful as a
+/// bound in generic functions.
+///
+/// ```
+/// use saturating_cast::SaturatingInto;
+///
+/// fn to_u16(value: impl SaturatingInto<u16>) -> u16 {
+///     value.saturating_into()
+/// }
+///
+/// assert_eq!(u16::MAX, to_u16(70000_u32));
+/// assert_eq!(0, to_u16(-1_i8));
+/// assert_eq!(12, to_u16(12.9_f32));
+/// ```
+pub trait SaturatingInto<T> {
+    /// Performs a saturating conversion into the target type `T`.
+    fn saturating_into(self) -> T;
+}
+
+impl<S, T: SaturatingFrom<S>> SaturatingInto<T> for S {
+    #[inline]
+    fn saturating_into(self) -> T {
+        T::saturating_from(self)
+    }
+}
+
+/// Outcome of a saturating cast, returned by
+/// [`saturating_cast_outcome`][SaturatingCast::saturating_cast_outcome].
+///
+/// ```
+/// use saturating_cast::{SaturatingCast, Saturation};
+///
+/// assert_eq!((10, Saturation::Exact), 10_i32.saturating_cast_outcome::<u8>());
+/// assert_eq!((0, Saturation::ClampedToMin), (-10_i32).saturating_cast_outcome::<u8>());
+/// assert_eq!((255, Saturation::ClampedToMax), 1024_i32.saturating_cast_outcome::<u8>());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Saturation {
+    /// The source value was within the range of the target type.
+    #[default]
+    Exact,
+    /// The source value was less than the target minimum and was clamped to the
+    /// minimum.
+    ClampedToMin,
+    /// The source value was greater than the target maximum and was clamped to
+    /// the maximum.
+    ClampedToMax,
+    /// The source value was NaN and was converted to zero, which is not a bound
+    /// of the target type.
+    Nan,
+}
+
+impl Saturation {
+    /// Returns `true` if the source value was clamped to the target minimum or
+    /// maximum.
+    #[inline]
+    pub const fn is_clamped(self) -> bool {
+        matches!(self, Self::ClampedToMin | Self::ClampedToMax)
+    }
+
+    /// Returns the [`Bound`] that the source value was clamped to, or `None` if
+    /// the cast was exact or the source value was NaN.
+    #[inline]
+    pub const fn bound(self) -> Option<Bound> {
+        match self {
+            Self::Exact | Self::Nan => None,
+            Self::ClampedToMin => Some(Bound::Min),
+            Self::ClampedToMax => Some(Bound::Max),
+        }
+    }
+}
+
+/// Supporting trait for
+/// [`saturating_cast_outcome`][SaturatingCast::saturating_cast_outcome] which
+/// performs saturating conversion and reports whether the source value was
+/// clamped.
+///
+/// This trait is implemented between all integer and floating point
+/// primitives. Casts from floats report whether the source value was outside
+/// the range of the target type, so values which only lose their fractional
+/// part are exact. NaN and infinities cast to floats are also exact, while NaN
+/// cast to an integer is converted to zero and reported as [`Saturation::Nan`].
+///
+/// ```
+/// use saturating_cast::{SaturatingCast, Saturation};
+///
+/// assert_eq!((255_u8, Saturation::Exact), 255.9_f32.saturating_cast_outcome::<u8>());
+/// assert_eq!((255_u8, Saturation::ClampedToMax), 256.0_f32.saturating_cast_outcome::<u8>());
+/// assert_eq!((0_u8, Saturation::Nan), f32::NAN.saturating_cast_outcome::<u8>());
+/// assert_eq!((f32::MIN, Saturation::ClampedToMin), (-1e300_f64).saturating_cast_outcome());
+/// ```
+pub trait SaturationElement<T>: SaturatingElement<T> {
+    /// Clamp `self` to within the range of `T::MIN..=T::MAX`, then return that
+    /// value cast to the target type `T` along with the bound it was clamped to,
+    /// if any.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_saturation_element(self) -> (T, Saturation);
+}
+
 // Unsigned integer saturating conversions
 macro_rules! impl_lossless_casts {
     ($src: ty => $($target: ty),*) => {$(
@@ -283,16 +681,58 @@ impl_int_clamp_to_zero_with_larger_uint_max!(isize => u64, u128, usize);
 impl_int_clamp_to_smaller!(isize => u8, u16);
 impl_isize_casts!(u32);
 
+// Saturation outcomes. `TryFrom` fails exactly when the value is clamped, and
+// the saturated value is the bound that was exceeded.
+macro_rules! impl_saturation_outcome {
+    ($src: ty => $($target: ty),*) => {$(
+        impl SaturationElement<$target> for $src {
+            #[inline]
+            fn as_saturation_element(self) -> ($target, Saturation) {
+                let value = self.as_element();
+                let saturation = if <$target>::try_from(self).is_ok() {
+                    Saturation::Exact
+                } else if value == <$target>::MIN {
+                    Saturation::ClampedToMin
+                } else {
+                    Saturation::ClampedToMax
+                };
+                (value, saturation)
+            }
+        }
+    )*};
+}
+
+impl_saturation_outcome!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl_saturation_outcome!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_saturation_outcome!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 #[cfg(test)]
 mod casts {
-    use crate::SaturatingCast;
-
-    use core::{i128, i16, i32, i64, i8, isize};
-    use core::{u128, u16, u32, u64, u8, usize};
+    use crate::{saturate, SaturatingCast, SaturatingFrom, SaturatingInto};
 
     macro_rules! impl_test_all_casts {
         ($src: ty => $($target: ty),*) => {$(
             let _: $target = <$src>::MIN.saturating_cast::<$target>();
+            let _: ($target, _) = <$src>::MIN.saturating_cast_outcome::<$target>();
+            let _: ($target, $src) = <$src>::MIN.saturating_cast_excess::<$target>();
+            let _: $target = <$target>::saturating_from(<$src>::MIN);
+            let _: $target = <$src>::MIN.saturating_into();
+            let _: $target = saturate::<$target, _>(<$src>::MIN);
+            let mut x = <$src>::MIN;
+            let _: $target = (&x).saturating_cast::<$target>();
+            let _: $target = (&mut x).saturating_cast::<$target>();
+            let _: [$target; 2] = [<$src>::MIN, <$src>::MAX].saturating_cast::<[$target; 2]>();
+            let _: ($target, $target) = (<$src>::MIN, <$src>::MAX).saturating_cast::<($target, $target)>();
         )*};
     }
 
@@ -0,0 +1,261 @@
+//! Bulk saturating casts between slices which use SIMD instructions for
+//! narrowing conversions.
+//!
+//! Each function checks the lengths of the slices, then converts as much of
+//! the slice as possible with the widest instruction set supported by the CPU
+//! before finishing the remainder with [`saturating_cast_slice`]. The results
+//! are identical to [`saturating_cast_slice`] on every platform.
+//!
+//! On `x86_64`, the following instruction sets are used:
+//!
+//! | Function      | SSE2 | SSE4.1 | AVX2 |
+//! |---------------|:----:|:------:|:----:|
+//! | [`i32_to_i16`] | ✓    |        | ✓    |
+//! | [`i32_to_u16`] |      | ✓      | ✓    |
+//! | [`i16_to_i8`]  | ✓    |        | ✓    |
+//! | [`i16_to_u8`]  | ✓    |        | ✓    |
+//! | [`u16_to_u8`]  | ✓    |        | ✓    |
+//!
+//! With the `std` feature enabled, CPU features are detected at runtime.
+//! Otherwise, only the features enabled at compile time with
+//! `-C target-feature` or `-C target-cpu` are used. Other architectures always
+//! use the scalar fallback, which the compiler may still auto-vectorize.
+//!
+//! ```
+//! use saturating_cast::simd;
+//!
+//! let samples = [-40000_i32, -5, 0, 5, 40000];
+//! let mut pcm = [0_i16; 5];
+//! simd::i32_to_i16(&samples, &mut pcm)?;
+//! assert_eq!([i16::MIN, -5, 0, 5, i16::MAX], pcm);
+//! # Ok::<(), saturating_cast::LengthError>(())
+//! ```
+// This module and the `vec` module are the only places in the crate where
+// `unsafe` is allowed. Every unsafe block must state why the target features it
+// relies on are available and why its memory accesses are in bounds.
+#![allow(unsafe_code)]
+#![deny(clippy::undocumented_unsafe_blocks)]
+
+use crate::{saturating_cast_slice, LengthError, SaturatingElement};
+
+// Converts a prefix of `src` into `dst`, returning the number of elements
+// which were converted. Kernels must only be called when the CPU supports
+// their target features.
+type Kernel<S, T> = unsafe fn(&[S], &mut [T]) -> usize;
+
+#[inline]
+fn narrow<S, T>(src: &[S], dst: &mut [T], kernel: Option<Kernel<S, T>>) -> Result<(), LengthError>
+where
+    S: SaturatingElement<T> + Copy,
+{
+    if src.len() != dst.len() {
+        return Err(LengthError::new(src.len(), dst.len()));
+    }
+
+    let converted = match kernel {
+        // SAFETY: Kernels are only selected by `select_kernel!` after their
+        // target features have been detected.
+        Some(kernel) => unsafe { kernel(src, dst) },
+        None => 0,
+    };
+
+    saturating_cast_slice(&src[converted..], &mut dst[converted..])
+}
+
+// Returns the first kernel whose target feature is supported, detecting
+// features at runtime when `std` is available.
+#[cfg(target_arch = "x86_64")]
+macro_rules! select_kernel {
+    ($($kernel: path => $feature: tt),+) => {{
+        #[cfg(feature = "std")]
+        let kernel = $(if std::is_x86_feature_detected!($feature) {
+            Some($kernel as Kernel<_, _>)
+        } else)+ {
+            None
+        };
+        #[cfg(not(feature = "std"))]
+        let kernel = $(if cfg!(target_feature = $feature) {
+            Some($kernel as Kernel<_, _>)
+        } else)+ {
+            None
+        };
+        kernel
+    }};
+}
+
+macro_rules! impl_simd_narrow {
+    ($name: ident, $src: ty => $target: ty, [$($kernel: ident => $feature: tt),+]) => {
+        #[doc = concat!(
+            "Performs a saturating cast of every element of `src` from `", stringify!($src),
+            "` to `", stringify!($target), "`, writing the results to `dst`.\n\n",
+            "Returns a [`LengthError`] without modifying `dst` if the slices have different ",
+            "lengths."
+        )]
+        #[inline]
+        pub fn $name(src: &[$src], dst: &mut [$target]) -> Result<(), LengthError> {
+            #[cfg(target_arch = "x86_64")]
+            let kernel = select_kernel!($(x86::$kernel => $feature),+);
+            #[cfg(not(target_arch = "x86_64"))]
+            let kernel = None;
+
+            narrow(src, dst, kernel)
+        }
+    };
+}
+
+impl_simd_narrow!(i32_to_i16, i32 => i16, [i32_to_i16_avx2 => "avx2", i32_to_i16_sse2 => "sse2"]);
+impl_simd_narrow!(i32_to_u16, i32 => u16, [i32_to_u16_avx2 => "avx2", i32_to_u16_sse41 => "sse4.1"]);
+impl_simd_narrow!(i16_to_i8, i16 => i8, [i16_to_i8_avx2 => "avx2", i16_to_i8_sse2 => "sse2"]);
+impl_simd_narrow!(i16_to_u8, i16 => u8, [i16_to_u8_avx2 => "avx2", i16_to_u8_sse2 => "sse2"]);
+impl_simd_narrow!(u16_to_u8, u16 => u8, [u16_to_u8_avx2 => "avx2", u16_to_u8_sse2 => "sse2"]);
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::*;
+    use core::mem::size_of;
+
+    // Each kernel loads two vectors of source elements and packs them into one
+    // vector of target elements, which are half the width of the source.
+    //
+    // The 256-bit pack instructions operate on each 128-bit half separately,
+    // so the 64-bit quarters of the result are reordered from `a0 b0 a1 b1` to
+    // `a0 a1 b0 b1` with `_mm256_permute4x64_epi64`.
+    macro_rules! impl_x86_kernel {
+        (
+            $name: ident, $feature: tt, $src: ty => $target: ty,
+            $vector: ty, $load: ident, $store: ident, |$a: ident, $b: ident| $pack: expr
+        ) => {
+            #[target_feature(enable = $feature)]
+            pub(super) unsafe fn $name(src: &[$src], dst: &mut [$target]) -> usize {
+                const LANES: usize = size_of::<$vector>() / size_of::<$target>();
+
+                let mut converted = 0;
+                for (src, dst) in src.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+                    // SAFETY: `src` holds `LANES` source elements, which is two
+                    // vectors, and `dst` holds `LANES` target elements, which
+                    // is one vector. The unaligned loads and store don't
+                    // require any alignment.
+                    unsafe {
+                        let $a = $load(src.as_ptr().cast());
+                        let $b = $load(src.as_ptr().add(LANES / 2).cast());
+                        $store(dst.as_mut_ptr().cast(), $pack);
+                    }
+                    converted += LANES;
+                }
+                converted
+            }
+        };
+    }
+
+    impl_x86_kernel!(i32_to_i16_sse2, "sse2", i32 => i16, __m128i, _mm_loadu_si128, _mm_storeu_si128,
+        |a, b| _mm_packs_epi32(a, b));
+    impl_x86_kernel!(i32_to_i16_avx2, "avx2", i32 => i16, __m256i, _mm256_loadu_si256, _mm256_storeu_si256,
+        |a, b| _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packs_epi32(a, b)));
+
+    impl_x86_kernel!(i32_to_u16_sse41, "sse4.1", i32 => u16, __m128i, _mm_loadu_si128, _mm_storeu_si128,
+        |a, b| _mm_packus_epi32(a, b));
+    impl_x86_kernel!(i32_to_u16_avx2, "avx2", i32 => u16, __m256i, _mm256_loadu_si256, _mm256_storeu_si256,
+        |a, b| _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packus_epi32(a, b)));
+
+    impl_x86_kernel!(i16_to_i8_sse2, "sse2", i16 => i8, __m128i, _mm_loadu_si128, _mm_storeu_si128,
+        |a, b| _mm_packs_epi16(a, b));
+    impl_x86_kernel!(i16_to_i8_avx2, "avx2", i16 => i8, __m256i, _mm256_loadu_si256, _mm256_storeu_si256,
+        |a, b| _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packs_epi16(a, b)));
+
+    impl_x86_kernel!(i16_to_u8_sse2, "sse2", i16 => u8, __m128i, _mm_loadu_si128, _mm_storeu_si128,
+        |a, b| _mm_packus_epi16(a, b));
+    impl_x86_kernel!(i16_to_u8_avx2, "avx2", i16 => u8, __m256i, _mm256_loadu_si256, _mm256_storeu_si256,
+        |a, b| _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packus_epi16(a, b)));
+
+    // The unsigned pack instructions treat their input as signed, so values
+    // are first clamped to `u8::MAX`. SSE2 has no unsigned 16-bit minimum, but
+    // `x - (x -| 255)` with saturating subtraction is equivalent.
+    impl_x86_kernel!(u16_to_u8_sse2, "sse2", u16 => u8, __m128i, _mm_loadu_si128, _mm_storeu_si128,
+    |a, b| {
+        let max = _mm_set1_epi16(0xff);
+        let a = _mm_sub_epi16(a, _mm_subs_epu16(a, max));
+        let b = _mm_sub_epi16(b, _mm_subs_epu16(b, max));
+        _mm_packus_epi16(a, b)
+    });
+    impl_x86_kernel!(u16_to_u8_avx2, "avx2", u16 => u8, __m256i, _mm256_loadu_si256, _mm256_storeu_si256,
+    |a, b| {
+        let max = _mm256_set1_epi16(0xff);
+        let a = _mm256_min_epu16(a, max);
+        let b = _mm256_min_epu16(b, max);
+        _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packus_epi16(a, b))
+    });
+}
+
+#[cfg(test)]
+mod casts {
+    use crate::{saturating_cast_slice, simd};
+
+    // Values around every bound of the source and target types.
+    const EDGES: [i64; 16] = [
+        i32::MIN as i64,
+        i16::MIN as i64 - 1,
+        i16::MIN as i64,
+        i8::MIN as i64 - 1,
+        i8::MIN as i64,
+        -1,
+        0,
+        1,
+        i8::MAX as i64,
+        i8::MAX as i64 + 1,
+        u8::MAX as i64,
+        u8::MAX as i64 + 1,
+        i16::MAX as i64,
+        i16::MAX as i64 + 1,
+        u16::MAX as i64,
+        i32::MAX as i64,
+    ];
+
+    macro_rules! impl_test_kernels {
+        ($test: ident, $function: ident, $src: ty => $target: ty, [$($kernel: ident => $feature: tt),*]) => {
+            #[test]
+            fn $test() {
+                use crate::SaturatingCast;
+
+                // Lengths which leave a remainder after every vector width.
+                let src: Vec<$src> = EDGES
+                    .iter()
+                    .cycle()
+                    .take(EDGES.len() * 7 + 5)
+                    .enumerate()
+                    .map(|(i, &x)| x.wrapping_add(i as i64 % 3 - 1).saturating_cast::<$src>())
+                    .collect();
+
+                for len in 0..src.len() {
+                    let src = &src[..len];
+                    let mut expected = vec![<$target>::default(); len];
+                    saturating_cast_slice(src, &mut expected).unwrap();
+
+                    let mut dst = vec![<$target>::default(); len];
+                    simd::$function(src, &mut dst).unwrap();
+                    assert_eq!(expected, dst);
+
+                    #[cfg(target_arch = "x86_64")]
+                    {$(
+                        if std::is_x86_feature_detected!($feature) {
+                            let mut dst = vec![<$target>::default(); len];
+                            // SAFETY: The target feature of the kernel was
+                            // detected.
+                            let converted = unsafe { super::x86::$kernel(src, &mut dst) };
+                            assert!(converted <= len && len - converted < 32);
+                            assert_eq!(expected[..converted], dst[..converted]);
+                        }
+                    )*}
+                }
+
+                let mut short = vec![<$target>::default(); 3];
+                assert!(simd::$function(&src[..4], &mut short).is_err());
+            }
+        };
+    }
+
+    impl_test_kernels!(i32_to_i16, i32_to_i16, i32 => i16, [i32_to_i16_avx2 => "avx2", i32_to_i16_sse2 => "sse2"]);
+    impl_test_kernels!(i32_to_u16, i32_to_u16, i32 => u16, [i32_to_u16_avx2 => "avx2", i32_to_u16_sse41 => "sse4.1"]);
+    impl_test_kernels!(i16_to_i8, i16_to_i8, i16 => i8, [i16_to_i8_avx2 => "avx2", i16_to_i8_sse2 => "sse2"]);
+    impl_test_kernels!(i16_to_u8, i16_to_u8, i16 => u8, [i16_to_u8_avx2 => "avx2", i16_to_u8_sse2 => "sse2"]);
+    impl_test_kernels!(u16_to_u8, u16_to_u8, u16 => u8, [u16_to_u8_avx2 => "avx2", u16_to_u8_sse2 => "sse2"]);
+}
@@ -0,0 +1,149 @@
+//! Module for saturating casts between slices.
+
+use crate::{LengthError, SaturatingElement, SaturationElement, SaturationStats};
+
+/// Performs a saturating cast of every element of `src`, writing the results
+/// to the element at the same index of `dst`.
+///
+/// Returns a [`LengthError`] without modifying `dst` if the slices have
+/// different lengths.
+///
+/// The loop has no bounds checks or early exits after the lengths are
+/// compared, so the compiler can auto-vectorize casts between primitives.
+///
+/// ```
+/// use saturating_cast::saturating_cast_slice;
+///
+/// let samples = [-40000_i32, -5, 0, 5, 40000];
+/// let mut pcm = [0_i16; 5];
+/// saturating_cast_slice(&samples, &mut pcm)?;
+/// assert_eq!([i16::MIN, -5, 0, 5, i16::MAX], pcm);
+///
+/// let mut short = [0_i16; 4];
+/// assert!(saturating_cast_slice(&samples, &mut short).is_err());
+/// # Ok::<(), saturating_cast::LengthError>(())
+/// ```
+#[inline]
+pub fn saturating_cast_slice<S, T>(src: &[S], dst: &mut [T]) -> Result<(), LengthError>
+where
+    S: SaturatingElement<T> + Copy,
+{
+    if src.len() != dst.len() {
+        return Err(LengthError::new(src.len(), dst.len()));
+    }
+
+    for (target, &value) in dst.iter_mut().zip(src) {
+        *target = value.as_element();
+    }
+
+    Ok(())
+}
+
+/// Performs a saturating cast of every element of `src`, writing the results
+/// to the element at the same index of `dst` and returning
+/// [`SaturationStats`] describing which elements were clamped.
+///
+/// Returns a [`LengthError`] without modifying `dst` if the slices have
+/// different lengths.
+///
+/// ```
+/// use saturating_cast::saturating_cast_slice_stats;
+///
+/// let mut dst = [0_u8; 4];
+/// let stats = saturating_cast_slice_stats(&[-1_i32, 128, 1024, 2048], &mut dst)?;
+/// assert_eq!([0, 128, 255, 255], dst);
+/// assert_eq!((1, 2), (stats.clamped_to_min(), stats.clamped_to_max()));
+/// assert_eq!((Some(-1), Some(2048)), (stats.min(), stats.max()));
+/// # Ok::<(), saturating_cast::LengthError>(())
+/// ```
+#[inline]
+pub fn saturating_cast_slice_stats<S, T>(
+    src: &[S],
+    dst: &mut [T],
+) -> Result<SaturationStats<S>, LengthError>
+where
+    S: SaturationElement<T> + PartialOrd + Copy,
+{
+    if src.len() != dst.len() {
+        return Err(LengthError::new(src.len(), dst.len()));
+    }
+
+    let mut stats = SaturationStats::new();
+    for (target, &value) in dst.iter_mut().zip(src) {
+        let (element, saturation) = value.as_saturation_element();
+        *target = element;
+        stats.record(value, saturation);
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod casts {
+    use crate::{saturating_cast_slice, saturating_cast_slice_stats, SaturatingCast};
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let src = [<$src>::MIN, <$src>::MAX];
+            let mut dst = [<$target>::default(); 2];
+            assert_eq!(Ok(()), saturating_cast_slice(&src, &mut dst));
+            assert_eq!(src.map(SaturatingCast::saturating_cast::<$target>), dst);
+        )*};
+    }
+
+    macro_rules! impl_test_all_stats {
+        ($src: ty => $($target: ty),*) => {$(
+            let src = [<$src>::MIN, <$src>::MAX];
+            let mut dst = [<$target>::default(); 2];
+            let stats = saturating_cast_slice_stats(&src, &mut dst).unwrap();
+            assert_eq!(src.map(SaturatingCast::saturating_cast::<$target>), dst);
+            assert_eq!((2, Some(<$src>::MIN), Some(<$src>::MAX)), (stats.count(), stats.min(), stats.max()));
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn uint_all_stats() {
+        impl_test_all_stats!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn int_all_stats() {
+        impl_test_all_stats!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        impl_test_all_stats!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+}
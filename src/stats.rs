@@ -0,0 +1,148 @@
+//! Module for collecting saturation statistics over many casts.
+
+use crate::Saturation;
+
+/// Accumulator counting how many casts were clamped to each bound of the
+/// target type and how many converted NaN to zero, along with the extreme
+/// source values seen.
+///
+/// Statistics are returned by
+/// [`saturating_cast_slice_stats`][crate::saturating_cast_slice_stats] and
+/// collected by the
+/// [`saturating_cast_stats`][crate::SaturatingCastIterator::saturating_cast_stats]
+/// iterator adapter. Statistics for separate chunks of a buffer can be combined
+/// with [`merge`][SaturationStats::merge].
+///
+/// ```
+/// use saturating_cast::{saturating_cast_slice_stats, SaturationStats};
+///
+/// let samples = [-40000_i32, -5, 0, 5, 40000, 50000];
+/// let mut pcm = [0_i16; 6];
+///
+/// let mut stats = SaturationStats::new();
+/// for (src, dst) in samples.chunks(4).zip(pcm.chunks_mut(4)) {
+///     stats.merge(saturating_cast_slice_stats(src, dst)?);
+/// }
+///
+/// assert_eq!(6, stats.count());
+/// assert_eq!(1, stats.clamped_to_min());
+/// assert_eq!(2, stats.clamped_to_max());
+/// assert_eq!(Some(-40000), stats.min());
+/// assert_eq!(Some(50000), stats.max());
+/// # Ok::<(), saturating_cast::LengthError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SaturationStats<S> {
+    count: usize,
+    clamped_to_min: usize,
+    clamped_to_max: usize,
+    nan: usize,
+    min: Option<S>,
+    max: Option<S>,
+}
+
+impl<S> SaturationStats<S> {
+    /// Create an accumulator which hasn't recorded any casts.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { count: 0, clamped_to_min: 0, clamped_to_max: 0, nan: 0, min: None, max: None }
+    }
+
+    /// Returns the number of casts which were recorded.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of casts which were clamped to the target minimum.
+    #[inline]
+    pub const fn clamped_to_min(&self) -> usize {
+        self.clamped_to_min
+    }
+
+    /// Returns the number of casts which were clamped to the target maximum.
+    #[inline]
+    pub const fn clamped_to_max(&self) -> usize {
+        self.clamped_to_max
+    }
+
+    /// Returns the number of casts which were clamped to either bound of the
+    /// target type.
+    #[inline]
+    pub const fn clamped(&self) -> usize {
+        self.clamped_to_min + self.clamped_to_max
+    }
+
+    /// Returns the number of casts of NaN which were converted to zero.
+    #[inline]
+    pub const fn nan(&self) -> usize {
+        self.nan
+    }
+}
+
+impl<S: PartialOrd + Copy> SaturationStats<S> {
+    /// Returns the smallest source value recorded, or `None` if no values were
+    /// recorded. Values which are unordered with themselves, such as NaN, are
+    /// not tracked.
+    #[inline]
+    pub fn min(&self) -> Option<S> {
+        self.min
+    }
+
+    /// Returns the largest source value recorded, or `None` if no values were
+    /// recorded. Values which are unordered with themselves, such as NaN, are
+    /// not tracked.
+    #[inline]
+    pub fn max(&self) -> Option<S> {
+        self.max
+    }
+
+    /// Record a cast of the source `value` with the given `saturation`
+    /// outcome.
+    #[inline]
+    pub fn record(&mut self, value: S, saturation: Saturation) {
+        self.count += 1;
+        match saturation {
+            Saturation::Exact => {}
+            Saturation::ClampedToMin => self.clamped_to_min += 1,
+            Saturation::ClampedToMax => self.clamped_to_max += 1,
+            Saturation::Nan => self.nan += 1,
+        }
+        self.record_extremes(value, value);
+    }
+
+    /// Combine the statistics of `other` into `self`, as if every cast recorded
+    /// by `other` had been recorded by `self`.
+    #[inline]
+    pub fn merge(&mut self, other: Self) {
+        self.count += other.count;
+        self.clamped_to_min += other.clamped_to_min;
+        self.clamped_to_max += other.clamped_to_max;
+        self.nan += other.nan;
+        if let (Some(min), Some(max)) = (other.min, other.max) {
+            self.record_extremes(min, max);
+        }
+    }
+
+    #[inline]
+    fn record_extremes(&mut self, min: S, max: S) {
+        if min.partial_cmp(&min).is_none() || max.partial_cmp(&max).is_none() {
+            return;
+        }
+        match self.min {
+            Some(current) if current <= min => {}
+            _ => self.min = Some(min),
+        }
+        match self.max {
+            Some(current) if current >= max => {}
+            _ => self.max = Some(max),
+        }
+    }
+}
+
+impl<S> Default for SaturationStats<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,302 @@
+//! Module for saturating casts of raw binary streams.
+
+use std::io::{self, Read, Write};
+
+use crate::{SaturatingElement, SaturationElement, SaturationStats};
+
+// Size of the input and output buffers, which bounds the memory used by a
+// conversion regardless of the length of the stream.
+const BUFFER_LEN: usize = 8192;
+
+/// Byte order of the elements in a raw binary stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Least significant byte first.
+    #[default]
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endian {
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+}
+
+/// Supporting trait for [`StreamConverter`] which reads and writes primitives
+/// as raw bytes in either byte order.
+///
+/// This trait is implemented for all integer and floating point primitives.
+///
+/// ```
+/// use saturating_cast::{Endian, RawElement};
+///
+/// let mut bytes = [0; 2];
+/// 0x1234_u16.write_bytes(&mut bytes, Endian::Big);
+/// assert_eq!([0x12, 0x34], bytes);
+/// assert_eq!(0x3412, u16::from_bytes(&bytes, Endian::Little));
+/// ```
+pub trait RawElement: Copy {
+    /// Number of bytes in the raw representation.
+    const SIZE: usize;
+
+    /// Read a value from `bytes` in the given byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not `SIZE`.
+    fn from_bytes(bytes: &[u8], endian: Endian) -> Self;
+
+    /// Write `self` to `bytes` in the given byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` is not `SIZE`.
+    fn write_bytes(self, bytes: &mut [u8], endian: Endian);
+}
+
+macro_rules! impl_raw_element {
+    ($($ty: ty),*) => {$(
+        impl RawElement for $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+
+            #[inline]
+            fn from_bytes(bytes: &[u8], endian: Endian) -> Self {
+                let mut array = [0; core::mem::size_of::<$ty>()];
+                array.copy_from_slice(bytes);
+                match endian {
+                    Endian::Little => <$ty>::from_le_bytes(array),
+                    Endian::Big => <$ty>::from_be_bytes(array),
+                }
+            }
+
+            #[inline]
+            fn write_bytes(self, bytes: &mut [u8], endian: Endian) {
+                let array = match endian {
+                    Endian::Little => self.to_le_bytes(),
+                    Endian::Big => self.to_be_bytes(),
+                };
+                bytes.copy_from_slice(&array);
+            }
+        }
+    )*};
+}
+
+impl_raw_element!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Streaming converter which reads raw elements of one type from a reader and
+/// writes them, saturated to another type, to a writer.
+///
+/// The stream is processed through fixed-size buffers, so files of any length
+/// are converted in constant memory. Reads and writes are made in blocks of up
+/// to 8 KiB, so the reader and writer don't need to be wrapped in `BufReader`
+/// or `BufWriter`.
+///
+/// ```
+/// use saturating_cast::{Endian, StreamConverter};
+///
+/// let capture: Vec<u8> = [-40000_i32, 5, 40000].iter().flat_map(|x| x.to_le_bytes()).collect();
+/// let mut pcm = Vec::new();
+///
+/// let converter = StreamConverter::new().output_endian(Endian::Big);
+/// let count = converter.convert::<i32, i16>(capture.as_slice(), &mut pcm)?;
+/// assert_eq!(3, count);
+/// assert_eq!([0x80, 0x00, 0x00, 0x05, 0x7f, 0xff], pcm.as_slice());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StreamConverter {
+    input_endian: Endian,
+    output_endian: Endian,
+}
+
+impl StreamConverter {
+    /// Create a converter which reads and writes little-endian elements.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { input_endian: Endian::Little, output_endian: Endian::Little }
+    }
+
+    /// Set the byte order of the elements read from the input.
+    #[inline]
+    pub const fn input_endian(mut self, endian: Endian) -> Self {
+        self.input_endian = endian;
+        self
+    }
+
+    /// Set the byte order of the elements written to the output.
+    #[inline]
+    pub const fn output_endian(mut self, endian: Endian) -> Self {
+        self.output_endian = endian;
+        self
+    }
+
+    /// Read elements of type `S` from `reader` until the end of the stream,
+    /// writing each element saturated to type `T` to `writer`. Returns the
+    /// number of elements converted.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading or writing other than
+    /// [`io::ErrorKind::Interrupted`]. Returns an error of kind
+    /// [`io::ErrorKind::UnexpectedEof`] if the stream ends partway through an
+    /// element, after every complete element has been written.
+    pub fn convert<S, T>(&self, reader: impl Read, writer: impl Write) -> io::Result<u64>
+    where
+        S: RawElement + SaturatingElement<T>,
+        T: RawElement,
+    {
+        self.stream(reader, writer, S::as_element)
+    }
+
+    /// Read elements of type `S` from `reader` until the end of the stream,
+    /// writing each element saturated to type `T` to `writer`. Returns
+    /// [`SaturationStats`] describing which elements were clamped or were NaN.
+    ///
+    /// ```
+    /// use saturating_cast::StreamConverter;
+    ///
+    /// let capture: Vec<u8> = [-40000_i32, 5, 40000].iter().flat_map(|x| x.to_le_bytes()).collect();
+    /// let mut pcm = Vec::new();
+    ///
+    /// let stats = StreamConverter::new().convert_stats::<i32, i16>(capture.as_slice(), &mut pcm)?;
+    /// assert_eq!(3, stats.count());
+    /// assert_eq!((1, 1), (stats.clamped_to_min(), stats.clamped_to_max()));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`convert`][StreamConverter::convert].
+    pub fn convert_stats<S, T>(
+        &self,
+        reader: impl Read,
+        writer: impl Write,
+    ) -> io::Result<SaturationStats<S>>
+    where
+        S: RawElement + SaturationElement<T> + PartialOrd,
+        T: RawElement,
+    {
+        let mut stats = SaturationStats::new();
+        let _ = self.stream(reader, writer, |value: S| {
+            let (element, saturation) = value.as_saturation_element();
+            stats.record(value, saturation);
+            element
+        })?;
+        Ok(stats)
+    }
+
+    fn stream<S, T>(
+        &self,
+        mut reader: impl Read,
+        mut writer: impl Write,
+        mut cast: impl FnMut(S) -> T,
+    ) -> io::Result<u64>
+    where
+        S: RawElement,
+        T: RawElement,
+    {
+        let mut input = [0; BUFFER_LEN];
+        let mut output = [0; BUFFER_LEN];
+        let batch_len = BUFFER_LEN / T::SIZE * S::SIZE;
+        let mut filled = 0;
+        let mut count = 0;
+
+        loop {
+            let read = match reader.read(&mut input[filled..]) {
+                Ok(read) => read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            filled += read;
+
+            let complete = filled - filled % S::SIZE;
+            for batch in input[..complete].chunks(batch_len) {
+                let mut written = 0;
+                for (src, dst) in batch.chunks_exact(S::SIZE).zip(output.chunks_exact_mut(T::SIZE))
+                {
+                    cast(S::from_bytes(src, self.input_endian))
+                        .write_bytes(dst, self.output_endian);
+                    written += T::SIZE;
+                }
+                writer.write_all(&output[..written])?;
+            }
+            count += (complete / S::SIZE) as u64;
+
+            input.copy_within(complete..filled, 0);
+            filled -= complete;
+
+            if read == 0 {
+                break;
+            }
+        }
+
+        writer.flush()?;
+        if filled != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "stream ended partway through an element",
+            ));
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod casts {
+    use crate::{SaturatingCast, StreamConverter};
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let input = [<$src>::MIN.to_le_bytes(), <$src>::MAX.to_le_bytes()].concat();
+            let expected = [
+                <$src>::MIN.saturating_cast::<$target>().to_le_bytes(),
+                <$src>::MAX.saturating_cast::<$target>().to_le_bytes(),
+            ]
+            .concat();
+
+            let mut output = Vec::new();
+            let count = StreamConverter::new().convert::<$src, $target>(input.as_slice(), &mut output);
+            assert_eq!(2, count.unwrap());
+            assert_eq!(expected, output);
+
+            let mut output = Vec::new();
+            let stats = StreamConverter::new()
+                .convert_stats::<$src, $target>(input.as_slice(), &mut output)
+                .unwrap();
+            assert_eq!((2, Some(<$src>::MIN), Some(<$src>::MAX)), (stats.count(), stats.min(), stats.max()));
+            assert_eq!(expected, output);
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+}
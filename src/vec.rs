@@ -0,0 +1,120 @@
+//! Module for saturating casts between vectors.
+// `saturating_cast_vec` writes the converted elements over the source elements
+// in place, which needs `unsafe`. Every unsafe block must state why its memory
+// accesses are in bounds and why the elements it reads are initialized.
+#![allow(unsafe_code)]
+#![deny(clippy::undocumented_unsafe_blocks)]
+
+use alloc::vec::Vec;
+use core::mem::{align_of, size_of, ManuallyDrop};
+
+use crate::SaturatingElement;
+
+/// Performs a saturating cast of every element of `vec`, returning a vector of
+/// the results.
+///
+/// The allocation of `vec` is reused for the returned vector when `S` and `T`
+/// have the same size and alignment, such as `i32` and `u32` or `f64` and
+/// `i64`, so converting a large vector doesn't require a second buffer. Each
+/// element is converted in place and the capacity is unchanged.
+///
+/// Types with different layouts, such as `i32` and `i16`, can't share an
+/// allocation because memory must be deallocated with the layout it was
+/// allocated with. Those conversions allocate a new buffer.
+///
+/// If a conversion panics while the allocation is being reused, the
+/// allocation and the elements which haven't been converted are leaked.
+///
+/// ```
+/// use saturating_cast::saturating_cast_vec;
+///
+/// let accumulators = vec![-1_i32, 100, 70000];
+/// let ptr = accumulators.as_ptr() as usize;
+///
+/// let samples = saturating_cast_vec::<i32, u32>(accumulators);
+/// assert_eq!(vec![0, 100, 70000], samples);
+/// assert_eq!(ptr, samples.as_ptr() as usize);
+///
+/// let bytes: Vec<u8> = saturating_cast_vec(samples);
+/// assert_eq!(vec![0, 100, 255], bytes);
+/// ```
+#[inline]
+pub fn saturating_cast_vec<S, T>(vec: Vec<S>) -> Vec<T>
+where
+    S: SaturatingElement<T>,
+{
+    if !reuses_allocation::<S, T>() {
+        return vec.into_iter().map(SaturatingElement::as_element).collect();
+    }
+
+    // The source elements are moved out one at a time, so the vector must not
+    // drop them again
+    let mut vec = ManuallyDrop::new(vec);
+    let (src, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    let dst = src.cast::<T>();
+    for i in 0..len {
+        // SAFETY: `i < len`, so source element `i` is initialized, and it is
+        // read exactly once before target element `i` overwrites its bytes.
+        // Elements after `i` haven't been written yet.
+        let value = unsafe { src.add(i).read() };
+        let element = value.as_element();
+        // SAFETY: `T` has the same size and alignment as `S`, so target element
+        // `i` occupies exactly the bytes of source element `i`, which is in
+        // bounds of the allocation and was read above.
+        unsafe { dst.add(i).write(element) };
+    }
+
+    // SAFETY: The allocation was made by a `Vec<S>` with `capacity` elements,
+    // and `T` has the same size and alignment, so it has the same layout as an
+    // allocation of `capacity` elements of `T`. The first `len` elements were
+    // initialized above.
+    unsafe { Vec::from_raw_parts(dst, len, capacity) }
+}
+
+// A `Vec<S>` allocation can be reused for a `Vec<T>` when it would be
+// deallocated with the same layout. Zero-sized types don't allocate, so there
+// is nothing to reuse.
+#[inline]
+const fn reuses_allocation<S, T>() -> bool {
+    size_of::<S>() != 0 && size_of::<S>() == size_of::<T>() && align_of::<S>() == align_of::<T>()
+}
+
+#[cfg(test)]
+mod casts {
+    use alloc::vec;
+
+    use crate::{saturating_cast_vec, SaturatingCast};
+
+    macro_rules! impl_test_all_casts {
+        ($src: ty => $($target: ty),*) => {$(
+            let dst: vec::Vec<$target> = saturating_cast_vec(vec![<$src>::MIN, <$src>::MAX]);
+            assert_eq!(vec![<$src>::MIN.saturating_cast::<$target>(), <$src>::MAX.saturating_cast()], dst);
+        )*};
+    }
+
+    #[test]
+    fn uint_all_casts() {
+        impl_test_all_casts!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn int_all_casts() {
+        impl_test_all_casts!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+
+    #[test]
+    fn float_all_casts() {
+        impl_test_all_casts!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        impl_test_all_casts!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    }
+}
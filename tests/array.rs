@@ -0,0 +1,41 @@
+//! Tests for element-wise saturating casts of fixed-size arrays.
+
+use saturating_cast::{saturate, SaturatingCast, SaturatingFrom, SaturatingInto};
+
+#[test]
+fn integer_arrays() {
+    let pixel = [-20_i32, 100, 300, 255];
+    assert_eq!([0, 100, 255, 255], pixel.saturating_cast::<[u8; 4]>());
+    assert_eq!([-20, 100, 127, 127], pixel.saturating_cast::<[i8; 4]>());
+    assert_eq!([0_u8; 0], [0_i32; 0].saturating_cast::<[u8; 0]>());
+
+    let wide = [u128::MAX, 0, 1 << 40];
+    assert_eq!([u32::MAX, 0, u32::MAX], <[u32; 3]>::saturating_from(wide));
+    let narrow: [i64; 3] = wide.saturating_into();
+    assert_eq!([i64::MAX, 0, 1 << 40], narrow);
+}
+
+#[test]
+fn float_arrays() {
+    let vector = [f32::NAN, -1.5, 0.5, 1e10];
+    assert_eq!([0, 0, 0, u16::MAX], vector.saturating_cast::<[u16; 4]>());
+    assert_eq!([0, -1, 0, i32::MAX], saturate::<[i32; 4], _>(vector));
+
+    let doubles = [f64::MAX, -1e300, 1.0];
+    assert_eq!([f32::MAX, f32::MIN, 1.0], doubles.saturating_cast::<[f32; 3]>());
+    assert_eq!([f32::MAX, 0.0], [u128::MAX, 0].saturating_cast::<[f32; 2]>());
+}
+
+#[test]
+fn nested_and_borrowed() {
+    let block = [[-1_i16, 256], [128, 0]];
+    assert_eq!([[0, 255], [128, 0]], block.saturating_cast::<[[u8; 2]; 2]>());
+
+    let refs = [&-1_i32, &1024];
+    assert_eq!([0, 255], refs.saturating_cast::<[u8; 2]>());
+    assert_eq!([0, 255], (&[-1_i32, 1024]).saturating_cast::<[u8; 2]>());
+
+    let pixels = [[-1_i32, 70000], [5, 6]];
+    let bytes: Vec<[u16; 2]> = pixels.iter().map(saturate).collect();
+    assert_eq!(vec![[0, u16::MAX], [5, 6]], bytes);
+}
@@ -0,0 +1,135 @@
+//! Tests for checked, overflowing, wrapping, and strict conversions.
+
+use saturating_cast::{
+    Bound, CastError, CheckedCast, OverflowingCast, SaturatingCast, Saturation, StrictCast,
+    WrappingCast,
+};
+
+#[test]
+fn checked() {
+    assert_eq!(Some(u8::MAX), 255_i32.checked_cast::<u8>());
+    assert_eq!(None, 256_i32.checked_cast::<u8>());
+    assert_eq!(None, (-1_i8).checked_cast::<u128>());
+    assert_eq!(Some(i8::MIN), (-128_i64).checked_cast::<i8>());
+    assert_eq!(None, (-129_i64).checked_cast::<i8>());
+    assert_eq!(None, u128::MAX.checked_cast::<i128>());
+    assert_eq!(Some(i128::MAX), (u128::MAX >> 1).checked_cast::<i128>());
+    assert_eq!(Some(u64::MAX), u64::MAX.checked_cast::<u64>());
+    assert_eq!(Some(0_usize), 0_isize.checked_cast::<usize>());
+    assert_eq!(None, isize::MIN.checked_cast::<usize>());
+}
+
+#[test]
+fn overflowing() {
+    assert_eq!((255_u8, false), 255_u16.overflowing_cast::<u8>());
+    assert_eq!((0_u8, true), 256_u16.overflowing_cast::<u8>());
+    assert_eq!((-1_i8, true), 255_u8.overflowing_cast::<i8>());
+    assert_eq!((u32::MAX, true), (-1_i32).overflowing_cast::<u32>());
+    assert_eq!((i64::MIN, true), (1_u128 << 63).overflowing_cast::<i64>());
+    assert_eq!((i64::MIN, false), i64::MIN.overflowing_cast::<i64>());
+    assert_eq!((-1_i128, false), (-1_i8).overflowing_cast::<i128>());
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(0_u8, 256_i32.wrapping_cast::<u8>());
+    assert_eq!(u8::MAX, (-1_i32).wrapping_cast::<u8>());
+    assert_eq!(i8::MIN, 128_u64.wrapping_cast::<i8>());
+    assert_eq!(u128::MAX, (-1_isize).wrapping_cast::<u128>());
+    assert_eq!(-1_i16, u128::MAX.wrapping_cast::<i16>());
+    assert_eq!(12345_u32, 12345_u16.wrapping_cast::<u32>());
+}
+
+#[test]
+fn strict() {
+    assert_eq!(u8::MAX, 255_i32.strict_cast::<u8>());
+    assert_eq!(i16::MIN, i16::MIN.strict_cast::<i64>().strict_cast::<i16>());
+    assert_eq!(usize::from(u16::MAX), u16::MAX.strict_cast::<usize>());
+}
+
+#[test]
+#[should_panic(expected = "attempt to cast with overflow")]
+fn strict_overflow() {
+    let _ = 256_i32.strict_cast::<u8>();
+}
+
+#[test]
+#[should_panic(expected = "attempt to cast with overflow")]
+fn strict_underflow() {
+    let _ = (-1_i8).strict_cast::<u64>();
+}
+
+#[test]
+fn try_cast_errors() {
+    assert_eq!(Ok(u16::MAX), 65535_u32.try_cast::<u16>());
+
+    let err = 70000_u32.try_cast::<u16>().unwrap_err();
+    assert_eq!(70000, *err.value());
+    assert_eq!("u16", err.target());
+    assert_eq!(Some(Bound::Max), err.bound());
+    assert_eq!("value 70000 exceeds u16::MAX", err.to_string());
+    assert_eq!(70000, err.into_value());
+
+    let err = i64::MIN.try_cast::<i32>().unwrap_err();
+    assert_eq!(Some(Bound::Min), err.bound());
+    assert_eq!("value -9223372036854775808 is below i32::MIN", err.to_string());
+
+    let err = (-1_isize).try_cast::<usize>().unwrap_err();
+    assert_eq!("value -1 is below usize::MIN", err.to_string());
+
+    // Errors can be boxed as `dyn Error`
+    let err: Box<dyn std::error::Error> = Box::new(u128::MAX.try_cast::<i128>().unwrap_err());
+    assert_eq!("value 340282366920938463463374607431768211455 exceeds i128::MAX", err.to_string());
+}
+
+#[test]
+fn cast_error_from_saturation() {
+    let (_, saturation) = 300_u16.saturating_cast_outcome::<u8>();
+    let err = CastError::new::<u8>(300_u16, saturation.bound().unwrap());
+    assert_eq!(Err(err), 300_u16.try_cast::<u8>());
+    assert_eq!(None, Saturation::Exact.bound());
+
+    let err = CastError::new::<i16>(-1e9_f64, Bound::Min);
+    assert_eq!("value -1000000000 is below i16::MIN", err.to_string());
+}
+
+#[test]
+fn try_cast_floats() {
+    assert_eq!(Ok(255_u8), 255.0_f32.try_cast::<u8>());
+    assert_eq!(Ok(0_u8), (-0.0_f64).try_cast::<u8>());
+    assert_eq!(Ok(16777216.0_f32), 16777216_i32.try_cast::<f32>());
+    assert_eq!(Ok(f32::INFINITY), f64::INFINITY.try_cast::<f32>());
+    assert_eq!(Ok(i64::MIN), (i64::MIN as f64).try_cast::<i64>());
+    assert_eq!(Ok(f32::MAX), (f32::MAX as u128).try_cast::<f32>());
+
+    let err = 255.9_f32.try_cast::<u8>().unwrap_err();
+    assert!(err.is_inexact());
+    assert_eq!(None, err.bound());
+    let err = (-0.5_f32).try_cast::<u8>().unwrap_err();
+    assert_eq!("value -0.5 cannot be represented exactly as u8", err.to_string());
+    assert!(16777217_i32.try_cast::<f32>().unwrap_err().is_inexact());
+    assert!(1e-50_f64.try_cast::<f32>().unwrap_err().is_inexact());
+    assert!(u64::MAX.try_cast::<f32>().unwrap_err().is_inexact());
+    assert!((i64::MAX - 10).try_cast::<f64>().unwrap_err().is_inexact());
+
+    let err = 70000.0_f32.try_cast::<u16>().unwrap_err();
+    assert_eq!(Some(Bound::Max), err.bound());
+    assert_eq!("value 70000 exceeds u16::MAX", err.to_string());
+
+    let err = (-1e300_f64).try_cast::<f32>().unwrap_err();
+    assert_eq!(Some(Bound::Min), err.bound());
+    assert_eq!("f32", err.target());
+
+    let err = u128::MAX.try_cast::<f32>().unwrap_err();
+    assert_eq!(Some(Bound::Max), err.bound());
+
+    let err = f64::NAN.try_cast::<i32>().unwrap_err();
+    assert!(err.is_nan());
+    assert_eq!(None, err.bound());
+    assert!(err.value().is_nan());
+    assert_eq!("cannot cast NaN to i32", err.to_string());
+    assert!(!err.is_inexact());
+    assert!(!CastError::new::<u8>(-1.0_f32, Bound::Min).is_nan());
+    assert!(CastError::inexact::<u8>(0.5_f32).is_inexact());
+    assert!(f32::NAN.try_cast::<f64>().unwrap().is_nan());
+}
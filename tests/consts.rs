@@ -0,0 +1,112 @@
+//! Tests for `const fn` saturating conversions.
+
+use saturating_cast::{consts, SaturatingCast};
+
+// Compares against `saturating_cast` at the bounds of the source type and on
+// either side of the bounds of the target type.
+macro_rules! check_const_casts {
+    ($src: ty => $($name: ident: $target: ty),*) => {$(
+        let max = <$target>::MAX.saturating_cast::<$src>();
+        let min = <$target>::MIN.saturating_cast::<$src>();
+        let values = [
+            <$src>::MIN,
+            <$src>::MIN / 2,
+            0,
+            1,
+            <$src>::MAX / 2,
+            <$src>::MAX,
+            max.saturating_sub(1),
+            max,
+            max.saturating_add(1),
+            min.saturating_sub(1),
+            min,
+            min.saturating_add(1),
+        ];
+        for x in values {
+            assert_eq!(x.saturating_cast::<$target>(), consts::$name(x));
+        }
+    )*};
+}
+
+#[test]
+fn uint_const_casts() {
+    check_const_casts!(u8 =>
+        u8_to_u8: u8, u8_to_u16: u16, u8_to_u32: u32, u8_to_u64: u64, u8_to_u128: u128,
+        u8_to_usize: usize, u8_to_i8: i8, u8_to_i16: i16, u8_to_i32: i32, u8_to_i64: i64,
+        u8_to_i128: i128, u8_to_isize: isize
+    );
+    check_const_casts!(u16 =>
+        u16_to_u8: u8, u16_to_u16: u16, u16_to_u32: u32, u16_to_u64: u64, u16_to_u128: u128,
+        u16_to_usize: usize, u16_to_i8: i8, u16_to_i16: i16, u16_to_i32: i32, u16_to_i64: i64,
+        u16_to_i128: i128, u16_to_isize: isize
+    );
+    check_const_casts!(u32 =>
+        u32_to_u8: u8, u32_to_u16: u16, u32_to_u32: u32, u32_to_u64: u64, u32_to_u128: u128,
+        u32_to_usize: usize, u32_to_i8: i8, u32_to_i16: i16, u32_to_i32: i32, u32_to_i64: i64,
+        u32_to_i128: i128, u32_to_isize: isize
+    );
+    check_const_casts!(u64 =>
+        u64_to_u8: u8, u64_to_u16: u16, u64_to_u32: u32, u64_to_u64: u64, u64_to_u128: u128,
+        u64_to_usize: usize, u64_to_i8: i8, u64_to_i16: i16, u64_to_i32: i32, u64_to_i64: i64,
+        u64_to_i128: i128, u64_to_isize: isize
+    );
+    check_const_casts!(u128 =>
+        u128_to_u8: u8, u128_to_u16: u16, u128_to_u32: u32, u128_to_u64: u64, u128_to_u128: u128,
+        u128_to_usize: usize, u128_to_i8: i8, u128_to_i16: i16, u128_to_i32: i32, u128_to_i64: i64,
+        u128_to_i128: i128, u128_to_isize: isize
+    );
+    check_const_casts!(usize =>
+        usize_to_u8: u8, usize_to_u16: u16, usize_to_u32: u32, usize_to_u64: u64,
+        usize_to_u128: u128, usize_to_usize: usize, usize_to_i8: i8, usize_to_i16: i16,
+        usize_to_i32: i32, usize_to_i64: i64, usize_to_i128: i128, usize_to_isize: isize
+    );
+}
+
+#[test]
+fn int_const_casts() {
+    check_const_casts!(i8 =>
+        i8_to_u8: u8, i8_to_u16: u16, i8_to_u32: u32, i8_to_u64: u64, i8_to_u128: u128,
+        i8_to_usize: usize, i8_to_i8: i8, i8_to_i16: i16, i8_to_i32: i32, i8_to_i64: i64,
+        i8_to_i128: i128, i8_to_isize: isize
+    );
+    check_const_casts!(i16 =>
+        i16_to_u8: u8, i16_to_u16: u16, i16_to_u32: u32, i16_to_u64: u64, i16_to_u128: u128,
+        i16_to_usize: usize, i16_to_i8: i8, i16_to_i16: i16, i16_to_i32: i32, i16_to_i64: i64,
+        i16_to_i128: i128, i16_to_isize: isize
+    );
+    check_const_casts!(i32 =>
+        i32_to_u8: u8, i32_to_u16: u16, i32_to_u32: u32, i32_to_u64: u64, i32_to_u128: u128,
+        i32_to_usize: usize, i32_to_i8: i8, i32_to_i16: i16, i32_to_i32: i32, i32_to_i64: i64,
+        i32_to_i128: i128, i32_to_isize: isize
+    );
+    check_const_casts!(i64 =>
+        i64_to_u8: u8, i64_to_u16: u16, i64_to_u32: u32, i64_to_u64: u64, i64_to_u128: u128,
+        i64_to_usize: usize, i64_to_i8: i8, i64_to_i16: i16, i64_to_i32: i32, i64_to_i64: i64,
+        i64_to_i128: i128, i64_to_isize: isize
+    );
+    check_const_casts!(i128 =>
+        i128_to_u8: u8, i128_to_u16: u16, i128_to_u32: u32, i128_to_u64: u64, i128_to_u128: u128,
+        i128_to_usize: usize, i128_to_i8: i8, i128_to_i16: i16, i128_to_i32: i32, i128_to_i64: i64,
+        i128_to_i128: i128, i128_to_isize: isize
+    );
+    check_const_casts!(isize =>
+        isize_to_u8: u8, isize_to_u16: u16, isize_to_u32: u32, isize_to_u64: u64,
+        isize_to_u128: u128, isize_to_usize: usize, isize_to_i8: i8, isize_to_i16: i16,
+        isize_to_i32: i32, isize_to_i64: i64, isize_to_i128: i128, isize_to_isize: isize
+    );
+}
+
+#[test]
+fn const_context() {
+    const A: u8 = consts::i32_to_u8(-1);
+    const B: i16 = consts::u64_to_i16(u64::MAX);
+    const C: usize = consts::i128_to_usize(i128::MAX);
+    const D: i8 = consts::isize_to_i8(-100);
+    const E: [u8; 3] = [consts::u16_to_u8(255), consts::u16_to_u8(256), consts::i8_to_u8(-1)];
+
+    assert_eq!(0, A);
+    assert_eq!(i16::MAX, B);
+    assert_eq!(usize::MAX, C);
+    assert_eq!(-100, D);
+    assert_eq!([255, 255, 0], E);
+}
@@ -0,0 +1,161 @@
+//! Tests for saturating conversions with floating point source types.
+
+use saturating_cast::{F32Narrowing, NanError, SaturatingCast};
+
+#[test]
+fn f32_to_int() {
+    // Values are rounded toward zero
+    assert_eq!(1_u8, 1.9_f32.saturating_cast());
+    assert_eq!(-1_i8, (-1.9_f32).saturating_cast());
+    assert_eq!(0_u8, (-0.9_f32).saturating_cast());
+
+    // Out of range values saturate
+    assert_eq!(u8::MAX, 255.5_f32.saturating_cast());
+    assert_eq!(u8::MIN, (-1.0_f32).saturating_cast());
+    assert_eq!(i16::MAX, 1e9_f32.saturating_cast());
+    assert_eq!(i16::MIN, (-1e9_f32).saturating_cast());
+    assert_eq!(u64::MAX, f32::MAX.saturating_cast());
+    assert_eq!(i128::MIN, f32::MIN.saturating_cast());
+
+    // Infinities saturate
+    assert_eq!(u32::MAX, f32::INFINITY.saturating_cast());
+    assert_eq!(u32::MIN, f32::NEG_INFINITY.saturating_cast());
+    assert_eq!(isize::MAX, f32::INFINITY.saturating_cast());
+    assert_eq!(isize::MIN, f32::NEG_INFINITY.saturating_cast());
+}
+
+#[test]
+fn f64_to_int() {
+    assert_eq!(i32::MAX, 2147483647.9_f64.saturating_cast());
+    assert_eq!(i32::MAX, 2147483648.0_f64.saturating_cast());
+    assert_eq!(i32::MIN, (-2147483648.9_f64).saturating_cast());
+    assert_eq!(i32::MIN, (-2147483649.0_f64).saturating_cast());
+
+    assert_eq!(u64::MAX, 18446744073709551616.0_f64.saturating_cast());
+    assert_eq!(i64::MIN, f64::MIN.saturating_cast());
+
+    assert_eq!(usize::MAX, f64::INFINITY.saturating_cast());
+    assert_eq!(usize::MIN, f64::NEG_INFINITY.saturating_cast());
+    assert_eq!(i8::MAX, f64::INFINITY.saturating_cast());
+    assert_eq!(i8::MIN, f64::NEG_INFINITY.saturating_cast());
+}
+
+#[test]
+fn nan_policy() {
+    // Zero
+    assert_eq!(0_u8, f32::NAN.saturating_cast());
+    assert_eq!(0_i64, f64::NAN.saturating_cast());
+    assert_eq!(0_i64, (-f64::NAN).saturating_cast());
+
+    // Caller-supplied value
+    assert_eq!(u8::MAX, f32::NAN.saturating_cast_or(u8::MAX));
+    assert_eq!(-1_i64, f64::NAN.saturating_cast_or(-1));
+    assert_eq!(u8::MAX, 1e3_f32.saturating_cast_or(0));
+    assert_eq!(i64::MIN, f64::NEG_INFINITY.saturating_cast_or(0));
+
+    // Error
+    assert_eq!(Err(NanError), f32::NAN.try_saturating_cast::<u16>());
+    assert_eq!(Err(NanError), f64::NAN.try_saturating_cast::<i128>());
+    assert_eq!(Ok(u16::MAX), f32::INFINITY.try_saturating_cast::<u16>());
+    assert_eq!(Ok(-3_i128), (-3.5_f64).try_saturating_cast::<i128>());
+}
+
+#[test]
+fn int_to_f32() {
+    // u128 values above f32::MAX saturate instead of rounding to infinity
+    assert_eq!(f32::MAX, u128::MAX.saturating_cast::<f32>());
+    assert_eq!(f32::MAX, (f32::MAX as u128).saturating_cast::<f32>());
+    assert_eq!(f32::MAX, (f32::MAX as u128 + 1).saturating_cast::<f32>());
+    assert_eq!(f32::MAX, (u128::MAX - (1 << 103)).saturating_cast::<f32>());
+    assert_eq!(i128::MAX as f32, i128::MAX.saturating_cast::<f32>());
+    assert_eq!(i128::MIN as f32, i128::MIN.saturating_cast::<f32>());
+    assert!(u128::MAX.saturating_cast::<f32>().is_finite());
+
+    // Rounding to nearest with ties to even at the edge of precision
+    assert_eq!(16777216.0, 16777216_u32.saturating_cast::<f32>());
+    assert_eq!(16777216.0, 16777217_u32.saturating_cast::<f32>());
+    assert_eq!(16777218.0, 16777218_u32.saturating_cast::<f32>());
+    assert_eq!(16777220.0, 16777219_u32.saturating_cast::<f32>());
+    assert_eq!(-16777216.0, (-16777217_i32).saturating_cast::<f32>());
+
+    // The rounded value may be larger in magnitude than the source
+    assert_eq!(4294967296.0, u32::MAX.saturating_cast::<f32>());
+    assert_eq!(2147483648.0, i32::MAX.saturating_cast::<f32>());
+    assert_eq!(-2147483648.0, i32::MIN.saturating_cast::<f32>());
+    assert_eq!(18446744073709551616.0, u64::MAX.saturating_cast::<f32>());
+}
+
+#[test]
+fn int_to_f64() {
+    assert_eq!(u128::MAX as f64, u128::MAX.saturating_cast::<f64>());
+    assert!(u128::MAX.saturating_cast::<f64>().is_finite());
+    assert_eq!(i128::MIN as f64, i128::MIN.saturating_cast::<f64>());
+
+    // Integers up to 32 bits are exact
+    assert_eq!(4294967295.0, u32::MAX.saturating_cast::<f64>());
+    assert_eq!(-2147483648.0, i32::MIN.saturating_cast::<f64>());
+
+    // Rounding to nearest with ties to even at the edge of precision
+    assert_eq!(9007199254740992.0, 9007199254740993_u64.saturating_cast::<f64>());
+    assert_eq!(9007199254740996.0, 9007199254740995_i64.saturating_cast::<f64>());
+    assert_eq!(-9007199254740992.0, (-9007199254740993_i64).saturating_cast::<f64>());
+    assert_eq!(18446744073709551616.0, u64::MAX.saturating_cast::<f64>());
+    assert_eq!(9223372036854775808.0, i64::MAX.saturating_cast::<f64>());
+}
+
+#[test]
+fn f64_to_f32() {
+    // Finite values saturate instead of rounding to infinity
+    assert_eq!(f32::MAX, f64::MAX.saturating_cast::<f32>());
+    assert_eq!(f32::MIN, f64::MIN.saturating_cast::<f32>());
+    assert_eq!(f32::MAX, 1e39_f64.saturating_cast::<f32>());
+    assert_eq!(f32::MIN, (-1e39_f64).saturating_cast::<f32>());
+    assert_eq!(f32::MAX, f64::from(f32::MAX).saturating_cast::<f32>());
+    assert_eq!(1.5_f32, 1.5_f64.saturating_cast::<f32>());
+
+    // Non-finite values are passed through
+    assert_eq!(f32::INFINITY, f64::INFINITY.saturating_cast::<f32>());
+    assert_eq!(f32::NEG_INFINITY, f64::NEG_INFINITY.saturating_cast::<f32>());
+    assert!(f64::NAN.saturating_cast::<f32>().is_nan());
+
+    // Signed zeros and subnormals are passed through
+    assert!((-0.0_f64).saturating_cast::<f32>().is_sign_negative());
+    assert!(1e-40_f64.saturating_cast::<f32>().is_subnormal());
+
+    // Lossless and identity casts
+    assert_eq!(f64::from(f32::MAX), f32::MAX.saturating_cast::<f64>());
+    assert_eq!(f32::INFINITY, f32::INFINITY.saturating_cast::<f32>());
+    assert_eq!(f64::MAX, f64::MAX.saturating_cast::<f64>());
+}
+
+#[test]
+fn f32_narrowing() {
+    let default = F32Narrowing::new();
+    assert_eq!(default, F32Narrowing::default());
+    assert_eq!(f32::MAX, default.narrow(f64::MAX));
+    assert!(default.narrow(f64::NAN).is_nan());
+    assert!(default.narrow(-0.0).is_sign_negative());
+    assert!(default.narrow(-1e-40).is_subnormal());
+
+    // NaN
+    let nan_to_zero = F32Narrowing::new().nan_to_zero(true);
+    assert_eq!(0.0, nan_to_zero.narrow(f64::NAN));
+    assert!(nan_to_zero.narrow(-f64::NAN).is_sign_positive());
+    assert_eq!(f32::NEG_INFINITY, nan_to_zero.narrow(f64::NEG_INFINITY));
+
+    // Signed zero
+    let unsigned_zero = F32Narrowing::new().unsigned_zero(true);
+    assert!(unsigned_zero.narrow(-0.0).is_sign_positive());
+    assert!(unsigned_zero.narrow(-1e-50).is_sign_positive());
+    assert_eq!(-1.0, unsigned_zero.narrow(-1.0));
+
+    // Subnormals
+    let flush = F32Narrowing::new().flush_subnormals(true);
+    assert_eq!(0.0, flush.narrow(1e-40));
+    assert!(flush.narrow(-1e-40).is_sign_negative());
+    assert_eq!(f32::MIN_POSITIVE, flush.narrow(f64::from(f32::MIN_POSITIVE)));
+
+    let flush_unsigned = flush.unsigned_zero(true);
+    assert!(flush_unsigned.narrow(-1e-40).is_sign_positive());
+    assert_eq!(f32::MIN, flush_unsigned.narrow(f64::MIN));
+}
@@ -0,0 +1,93 @@
+//! Tests for the `SaturatingFrom` and `SaturatingInto` conversion traits.
+
+use saturating_cast::{
+    saturate, SaturatingCast, SaturatingElement, SaturatingFrom, SaturatingInto,
+};
+
+fn sum_u8<I: IntoIterator>(values: I) -> u8
+where
+    I::Item: SaturatingInto<u8>,
+{
+    values.into_iter().fold(0_u8, |acc, x| acc.saturating_add(x.saturating_into()))
+}
+
+#[test]
+fn saturating_from() {
+    assert_eq!(u8::MAX, u8::saturating_from(1024_i32));
+    assert_eq!(u8::MIN, u8::saturating_from(-1_i64));
+    assert_eq!(i16::MAX, i16::saturating_from(u128::MAX));
+    assert_eq!(u32::MAX, u32::saturating_from(f64::INFINITY));
+    assert_eq!(f32::MAX, f32::saturating_from(u128::MAX));
+    assert_eq!(isize::MIN, isize::saturating_from(i128::MIN));
+}
+
+#[test]
+fn saturating_into() {
+    let x: u16 = 70000_u32.saturating_into();
+    assert_eq!(u16::MAX, x);
+    let y: i8 = (-1e9_f32).saturating_into();
+    assert_eq!(i8::MIN, y);
+
+    assert_eq!(10, sum_u8([4_i32, -2, 6]));
+    assert_eq!(255, sum_u8([200_u64, 200]));
+    assert_eq!(2, sum_u8([1.9_f32, 1.9, -5.0]));
+}
+
+#[test]
+fn custom_types() {
+    #[derive(Clone, Copy)]
+    struct Int(i32);
+
+    #[derive(Debug, PartialEq)]
+    struct Uint(u8);
+
+    impl SaturatingElement<Uint> for Int {
+        fn as_element(self) -> Uint {
+            Uint(self.0.saturating_cast())
+        }
+    }
+
+    assert_eq!(Uint(255), Uint::saturating_from(Int(512)));
+    let x: Uint = Int(-1).saturating_into();
+    assert_eq!(Uint(0), x);
+}
+
+#[test]
+fn non_copy_types() {
+    // Ledger of signed amounts which saturates its total
+    struct Ledger(Vec<i64>);
+
+    impl SaturatingElement<i32> for &Ledger {
+        fn as_element(self) -> i32 {
+            self.0.iter().map(|&x| i128::from(x)).sum::<i128>().saturating_cast()
+        }
+    }
+
+    impl SaturatingElement<i32> for Ledger {
+        fn as_element(self) -> i32 {
+            (&self).as_element()
+        }
+    }
+
+    let ledger = Ledger(vec![i64::MAX, i64::MAX, -5]);
+    assert_eq!(i32::MAX, (&ledger).saturating_cast::<i32>());
+    assert_eq!(i32::MAX, i32::saturating_from(&ledger));
+    assert_eq!(i32::MAX, ledger.saturating_cast::<i32>());
+
+    let ledger = Ledger(vec![-3, 1]);
+    let total: i32 = ledger.saturating_into();
+    assert_eq!(-2, total);
+}
+
+#[test]
+fn function_pointers() {
+    let table: [fn(i32) -> u8; 2] = [saturate, |x| saturate(x.wrapping_neg())];
+    assert_eq!(255, table[0](1024));
+    assert_eq!(0, table[1](1024));
+
+    let to_i16: fn(f64) -> i16 = saturate::<i16, f64>;
+    assert_eq!(i16::MIN, to_i16(-1e9));
+
+    let words: Vec<u16> = [70000_u32, 7].iter().map(saturate).collect();
+    assert_eq!(vec![u16::MAX, 7], words);
+}
@@ -0,0 +1,85 @@
+//! Tests for the saturating cast iterator adapter.
+
+use core::iter::FusedIterator;
+
+use saturating_cast::SaturatingCastIterator;
+
+#[test]
+fn matches_map() {
+    let values = [i64::MIN, -129, -1, 0, 127, 255, 256, i64::MAX];
+
+    let adapted: Vec<u8> = values.into_iter().saturating_cast_each::<u8>().collect();
+    let mapped: Vec<u8> =
+        values.into_iter().map(saturating_cast::SaturatingCast::saturating_cast).collect();
+    assert_eq!(mapped, adapted);
+    assert_eq!(vec![0, 0, 0, 0, 127, 255, 255, 255], adapted);
+
+    let floats = [f64::NAN, f64::NEG_INFINITY, -0.5, 1.5, 1e10];
+    let adapted: Vec<i16> = floats.iter().saturating_cast_each::<i16>().collect();
+    assert_eq!(vec![0, i16::MIN, 0, 1, i16::MAX], adapted);
+}
+
+#[test]
+fn double_ended() {
+    let values = [-1_i32, 0, 128, 1024];
+
+    let mut iter = values.iter().saturating_cast_each::<u8>();
+    assert_eq!(Some(255), iter.next_back());
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(Some(128), iter.next_back());
+    assert_eq!(Some(0), iter.next_back());
+    assert_eq!(None, iter.next());
+
+    let reversed: Vec<i8> = values.into_iter().saturating_cast_each::<i8>().rev().collect();
+    assert_eq!(vec![127, 127, 0, -1], reversed);
+
+    assert_eq!(Some(128), values.iter().saturating_cast_each::<u8>().nth_back(1));
+    assert_eq!(Some(-1), values.iter().saturating_cast_each::<i8>().rfold(None, |_, x| Some(x)));
+}
+
+#[test]
+fn exact_size() {
+    let values = [300_u16; 5];
+
+    let mut iter = values.iter().saturating_cast_each::<u8>();
+    assert_eq!(5, iter.len());
+    assert_eq!(Some(255), iter.nth(2));
+    assert_eq!(2, iter.len());
+    assert_eq!((2, Some(2)), iter.size_hint());
+    assert_eq!(2, iter.count());
+
+    let filtered = values.iter().filter(|&&x| x > 0).saturating_cast_each::<u8>();
+    assert_eq!((0, Some(5)), filtered.size_hint());
+}
+
+#[test]
+fn fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut iter = [1_u32].into_iter().saturating_cast_each::<u8>();
+    assert_fused(&iter);
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn fold_and_inner() {
+    let sum = (0_i32..1000).saturating_cast_each::<u8>().fold(0_u32, |acc, x| acc + u32::from(x));
+    assert_eq!((0..256).sum::<u32>() + 255 * 744, sum);
+
+    let mut iter = (250_u32..260).saturating_cast_each::<u8>();
+    assert_eq!(Some(250), iter.next());
+    assert_eq!(251..260, iter.clone().into_inner());
+    assert_eq!(vec![251, 252, 253, 254, 255, 255, 255, 255, 255], iter.collect::<Vec<_>>());
+}
+
+#[test]
+fn with_saturating_cast_in_scope() {
+    use saturating_cast::SaturatingCast;
+
+    let values = [-1_i32, 128, 1024];
+    let bytes: Vec<u8> = values.iter().saturating_cast_each::<u8>().collect();
+    assert_eq!(vec![0, 128, 255], bytes);
+    assert_eq!(255_u8, values[2].saturating_cast::<u8>());
+}
@@ -0,0 +1,212 @@
+//! Tests for reporting whether saturating conversions clamped the value.
+
+use saturating_cast::{CheckedCast, SaturatingCast, Saturation};
+
+// The outcome is exact when the checked cast succeeds, otherwise the bound that
+// was clamped to is determined by the sign of the source value.
+macro_rules! check_outcomes {
+    ($src: ty => $($target: ty),*) => {$(
+        for x in [<$src>::MIN, <$src>::MIN / 2, 0, 1, <$src>::MAX / 2, <$src>::MAX] {
+            let (value, saturation) = x.saturating_cast_outcome::<$target>();
+            assert_eq!(x.saturating_cast::<$target>(), value);
+            let expected = match x.checked_cast::<$target>() {
+                Some(_) => Saturation::Exact,
+                None if x < 1 => Saturation::ClampedToMin,
+                None => Saturation::ClampedToMax,
+            };
+            assert_eq!(expected, saturation);
+        }
+    )*};
+}
+
+#[test]
+fn uint_outcomes() {
+    check_outcomes!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn int_outcomes() {
+    check_outcomes!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_outcomes!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn outcome_bounds() {
+    assert_eq!((255_u8, Saturation::Exact), 255_i32.saturating_cast_outcome());
+    assert_eq!((255_u8, Saturation::ClampedToMax), 256_i32.saturating_cast_outcome());
+    assert_eq!((0_u8, Saturation::Exact), 0_i32.saturating_cast_outcome());
+    assert_eq!((0_u8, Saturation::ClampedToMin), (-1_i32).saturating_cast_outcome());
+    assert_eq!((i8::MIN, Saturation::Exact), (-128_i16).saturating_cast_outcome());
+    assert_eq!((i8::MIN, Saturation::ClampedToMin), (-129_i16).saturating_cast_outcome());
+    assert_eq!((i64::MAX, Saturation::ClampedToMax), u64::MAX.saturating_cast_outcome());
+
+    assert!(!Saturation::Exact.is_clamped());
+    assert!(Saturation::ClampedToMin.is_clamped());
+    assert!(Saturation::ClampedToMax.is_clamped());
+}
+
+// The excess is zero for exact casts and otherwise has the sign of the bound
+// that was exceeded.
+macro_rules! check_excess {
+    ($src: ty => $($target: ty),*) => {$(
+        for x in [<$src>::MIN, <$src>::MIN / 2, 0, 1, <$src>::MAX / 2, <$src>::MAX] {
+            let (value, excess) = x.saturating_cast_excess::<$target>();
+            let (expected, saturation) = x.saturating_cast_outcome::<$target>();
+            assert_eq!(expected, value);
+            match saturation {
+                Saturation::Exact => assert_eq!(0, excess),
+                Saturation::ClampedToMin => assert!(excess < 1 && excess != 0),
+                Saturation::ClampedToMax => assert!(excess > 0),
+                Saturation::Nan => unreachable!(),
+            }
+        }
+    )*};
+}
+
+#[test]
+fn uint_excess() {
+    check_excess!(u8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(u16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(u32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(u64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(u128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(usize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn int_excess() {
+    check_excess!(i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(i16 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_excess!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn excess_values() {
+    assert_eq!((255_u8, 769_i32), 1024_i32.saturating_cast_excess());
+    assert_eq!((0_u8, -1024_i32), (-1024_i32).saturating_cast_excess());
+    assert_eq!((i8::MAX, 128_u8), u8::MAX.saturating_cast_excess());
+    assert_eq!((0_u128, i128::MIN), i128::MIN.saturating_cast_excess());
+    assert_eq!((i8::MIN, i128::MIN + 128), i128::MIN.saturating_cast_excess());
+    assert_eq!((i8::MAX, i128::MAX - 127), i128::MAX.saturating_cast_excess());
+    assert_eq!((u8::MAX, u128::MAX - 255), u128::MAX.saturating_cast_excess());
+    assert_eq!((i64::MAX, 1_u64 << 63), u64::MAX.saturating_cast_excess::<i64>());
+
+    // Carrying the excess forward loses nothing
+    let mut carry = 0_i32;
+    let mut total = 0_i32;
+    for x in [300, 300, -700, 100] {
+        let (value, excess) = (x + carry).saturating_cast_excess::<u8>();
+        carry = excess;
+        total += i32::from(value);
+    }
+    assert_eq!(0, total + carry);
+}
+
+macro_rules! check_float_outcome {
+    ($src: ty => $($target: ty),*) => {$(
+        let values = [
+            <$src>::NEG_INFINITY,
+            <$src>::MIN,
+            <$target>::MIN as $src - 1.0,
+            <$target>::MIN as $src,
+            <$target>::MIN as $src - 0.5,
+            -0.5,
+            0.0,
+            0.5,
+            <$target>::MAX as $src,
+            <$target>::MAX as $src + 0.5,
+            <$target>::MAX as $src + 1.0,
+            <$src>::MAX,
+            <$src>::INFINITY,
+            <$src>::NAN,
+        ];
+        for x in values {
+            let (value, saturation) = x.saturating_cast_outcome::<$target>();
+            assert_eq!(x.saturating_cast::<$target>(), value);
+
+            // Compare the truncated value against the exact bounds of the target
+            let truncated = f64::from(x).trunc();
+            let expected = if x.is_nan() {
+                Saturation::Nan
+            } else if truncated < <$target>::MIN as f64 {
+                Saturation::ClampedToMin
+            } else if truncated >= 2.0 * ((<$target>::MAX / 2 + 1) as f64) {
+                Saturation::ClampedToMax
+            } else {
+                Saturation::Exact
+            };
+            assert_eq!(expected, saturation, "{} as {}", x, stringify!($target));
+        }
+    )*};
+}
+
+#[test]
+fn float_outcome() {
+    check_float_outcome!(f32 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    check_float_outcome!(f64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    assert_eq!((255, Saturation::Exact), 255.5_f32.saturating_cast_outcome::<u8>());
+    assert_eq!((255, Saturation::ClampedToMax), 256.0_f64.saturating_cast_outcome::<u8>());
+    assert_eq!((0, Saturation::Exact), (-0.9_f64).saturating_cast_outcome::<u8>());
+    assert_eq!((i32::MAX, Saturation::Exact), 2147483647.0_f64.saturating_cast_outcome::<i32>());
+    assert_eq!(
+        (i32::MAX, Saturation::ClampedToMax),
+        2147483648.0_f32.saturating_cast_outcome::<i32>()
+    );
+    assert_eq!((2147483520, Saturation::Exact), 2147483520.0_f32.saturating_cast_outcome::<i32>());
+    assert_eq!((i32::MIN, Saturation::Exact), (-2147483648.0_f32).saturating_cast_outcome::<i32>());
+    assert_eq!(
+        (i32::MIN, Saturation::ClampedToMin),
+        (-2147483904.0_f32).saturating_cast_outcome::<i32>()
+    );
+    assert_eq!((i8::MIN, Saturation::Exact), (-128.9_f64).saturating_cast_outcome::<i8>());
+    assert_eq!((i8::MIN, Saturation::ClampedToMin), (-129.0_f64).saturating_cast_outcome::<i8>());
+    assert_eq!((0, Saturation::Nan), f32::NAN.saturating_cast_outcome::<i64>());
+    assert_eq!((0, Saturation::Nan), (-f64::NAN).saturating_cast_outcome::<u8>());
+    assert!(!Saturation::Nan.is_clamped());
+    assert_eq!(None, Saturation::Nan.bound());
+}
+
+#[test]
+fn to_float_outcome() {
+    assert_eq!((f32::MAX, Saturation::ClampedToMax), u128::MAX.saturating_cast_outcome::<f32>());
+    assert_eq!((f32::MAX, Saturation::Exact), (f32::MAX as u128).saturating_cast_outcome::<f32>());
+    assert_eq!((u128::MAX as f64, Saturation::Exact), u128::MAX.saturating_cast_outcome::<f64>());
+    assert_eq!((-128.0, Saturation::Exact), i8::MIN.saturating_cast_outcome::<f32>());
+
+    assert_eq!((f32::MAX, Saturation::ClampedToMax), 1e300_f64.saturating_cast_outcome::<f32>());
+    assert_eq!((f32::MIN, Saturation::ClampedToMin), f64::MIN.saturating_cast_outcome::<f32>());
+    assert_eq!((f32::INFINITY, Saturation::Exact), f64::INFINITY.saturating_cast_outcome::<f32>());
+    assert!(f64::NAN.saturating_cast_outcome::<f32>().0.is_nan());
+    assert_eq!((1.5, Saturation::Exact), 1.5_f32.saturating_cast_outcome::<f64>());
+
+    assert_eq!((255_u8, 0.0_f32), 255.5_f32.saturating_cast_excess::<u8>());
+    assert_eq!((255_u8, 44.5_f32), 299.5_f32.saturating_cast_excess::<u8>());
+    assert_eq!((0_u8, -3.0_f64), (-3.0_f64).saturating_cast_excess::<u8>());
+    let (value, excess) = f64::NAN.saturating_cast_excess::<i8>();
+    assert!(value == 0 && excess.is_nan());
+}
+
+// Rounding to the nearest float can move the result past the source value,
+// which isn't an excess and must not overflow when subtracted.
+#[test]
+fn to_float_excess() {
+    assert_eq!((16777220.0_f32, 0), 16777219_u64.saturating_cast_excess::<f32>());
+    assert_eq!((2f64.powi(63), 0), (i64::MAX - 10).saturating_cast_excess::<f64>());
+    assert_eq!((2f32.powi(64), 0), u64::MAX.saturating_cast_excess::<f32>());
+    assert_eq!((-2f32.powi(63), 0), i64::MIN.saturating_cast_excess::<f32>());
+    assert_eq!((f32::MAX, u128::MAX - f32::MAX as u128), u128::MAX.saturating_cast_excess::<f32>());
+    assert_eq!((f32::MAX, 1e300 - f64::from(f32::MAX)), 1e300_f64.saturating_cast_excess::<f32>());
+}
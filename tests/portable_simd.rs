@@ -0,0 +1,78 @@
+//! Tests for lane-wise saturating casts between `core::simd` vectors.
+#![cfg(feature = "portable_simd")]
+#![feature(portable_simd)]
+
+use core::simd::Simd;
+
+use saturating_cast::{saturating_cast_slice, SaturatingCast};
+
+macro_rules! impl_test_lanes {
+    ($test: ident, $src: ty => $($target: ty),*) => {
+        #[test]
+        fn $test() {
+            let edges = [
+                i64::MIN, i32::MIN as i64, i16::MIN as i64, -129, -128, -1, 0, 1, 127, 128, 255,
+                256, 65535, 65536, u32::MAX as i64, i64::MAX,
+            ];
+            let mut src = [<$src>::default(); 16];
+            saturating_cast_slice(&edges, &mut src).unwrap();
+            let vector = Simd::from_array(src);
+            $(
+                let mut expected = [<$target>::default(); 16];
+                saturating_cast_slice(&src, &mut expected).unwrap();
+                assert_eq!(expected, vector.saturating_cast::<Simd<$target, 16>>().to_array());
+            )*
+        }
+    };
+}
+
+impl_test_lanes!(u8_lanes, u8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(u16_lanes, u16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(u32_lanes, u32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(u64_lanes, u64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(usize_lanes, usize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(i8_lanes, i8 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(i16_lanes, i16 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(i32_lanes, i32 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(i64_lanes, i64 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_test_lanes!(isize_lanes, isize => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[test]
+fn float_lanes() {
+    let floats = [
+        f64::NAN,
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -1e300,
+        -1.5,
+        -0.0,
+        0.5,
+        2.5,
+        1e10,
+        f64::MAX,
+        f64::INFINITY,
+        255.9,
+    ];
+    let vector = Simd::from_array(floats);
+    assert_eq!(
+        floats.map(|x| x.saturating_cast::<i16>()),
+        vector.saturating_cast::<Simd<i16, 12>>().to_array()
+    );
+    assert_eq!(
+        floats.map(|x| x.saturating_cast::<u64>()),
+        vector.saturating_cast::<Simd<u64, 12>>().to_array()
+    );
+
+    let narrowed = vector.saturating_cast::<Simd<f32, 12>>().to_array();
+    let expected = floats.map(|x| x.saturating_cast::<f32>());
+    assert!(narrowed.iter().zip(&expected).all(|(a, b)| a.to_bits() == b.to_bits()));
+
+    let singles = Simd::from_array([f32::NAN, -1.0, 3.9, 1e20]);
+    assert_eq!([0, 0, 3, u8::MAX], singles.saturating_cast::<Simd<u8, 4>>().to_array());
+
+    let ints = Simd::from_array([i64::MAX, 16777217, -1, 0]);
+    assert_eq!(
+        [i64::MAX as f32, 16777216.0, -1.0, 0.0],
+        ints.saturating_cast::<Simd<f32, 4>>().to_array()
+    );
+}
@@ -0,0 +1,37 @@
+//! Tests for saturating conversions from references to primitives.
+
+use saturating_cast::{SaturatingCast, SaturatingFrom};
+
+#[test]
+fn iterator_map() {
+    let values = [-1_i32, 0, 128, 1024];
+
+    let bytes: Vec<u8> = values.iter().map(SaturatingCast::saturating_cast).collect();
+    assert_eq!(vec![0, 0, 128, 255], bytes);
+
+    let signed: Vec<i8> = values.iter().map(i8::saturating_from).collect();
+    assert_eq!(vec![-1, 0, 127, 127], signed);
+
+    let floats = [f32::NAN, -1.5, 300.0];
+    let bytes: Vec<u8> = floats.iter().map(|x| x.saturating_cast()).collect();
+    assert_eq!(vec![0, 0, 255], bytes);
+}
+
+#[test]
+fn mutable_references() {
+    let mut values = [u64::MAX, 5];
+    for x in values.iter_mut() {
+        assert!(x.saturating_cast::<u16>() >= 5);
+    }
+    assert_eq!(u16::MAX, (&mut values[0]).saturating_cast::<u16>());
+}
+
+#[test]
+fn reference_matrix() {
+    assert_eq!(u8::MAX, (&u128::MAX).saturating_cast::<u8>());
+    assert_eq!(i8::MIN, (&isize::MIN).saturating_cast::<i8>());
+    assert_eq!(0_usize, (&i16::MIN).saturating_cast::<usize>());
+    assert_eq!(f32::MAX, (&u128::MAX).saturating_cast::<f32>());
+    assert_eq!(f32::MAX, (&f64::MAX).saturating_cast::<f32>());
+    assert_eq!(i64::MIN, (&f64::NEG_INFINITY).saturating_cast::<i64>());
+}
@@ -0,0 +1,224 @@
+//! Tests for saturating conversions with a choice of rounding mode.
+
+use saturating_cast::{Rounding, SaturatingCast};
+
+const MODES: [Rounding; 5] = [
+    Rounding::NearestEven,
+    Rounding::NearestAwayFromZero,
+    Rounding::TowardZero,
+    Rounding::TowardPositive,
+    Rounding::TowardNegative,
+];
+
+// Checks that the rounded value is on the requested side of the source value and
+// that no float between the source and the rounded value was skipped.
+macro_rules! check_int_to_float {
+    ($x: expr, $float: ty) => {{
+        let x = $x;
+        let wide = i128::from(x);
+        for rounding in MODES {
+            let r: $float = x.saturating_cast_rounded(rounding);
+            let below = r.next_down().floor() as i128;
+            let above = r.next_up().ceil() as i128;
+            let r = r as i128;
+            match rounding {
+                Rounding::NearestEven => {
+                    assert_eq!(x.saturating_cast::<$float>() as i128, r);
+                }
+                Rounding::NearestAwayFromZero => {
+                    let nearest = x.saturating_cast::<$float>() as i128;
+                    assert_eq!((nearest - wide).abs(), (r - wide).abs());
+                    if r != nearest {
+                        assert!(r.abs() > nearest.abs());
+                    }
+                }
+                Rounding::TowardZero if wide >= 0 => assert!(r <= wide && above > wide),
+                Rounding::TowardZero => assert!(r >= wide && below < wide),
+                Rounding::TowardPositive => assert!(r >= wide && below < wide),
+                Rounding::TowardNegative => assert!(r <= wide && above > wide),
+            }
+        }
+    }};
+}
+
+#[test]
+fn int_to_f32_sides() {
+    for x in (1_i32 << 24) - 16..(1_i32 << 24) + 64 {
+        check_int_to_float!(x, f32);
+        check_int_to_float!(-x, f32);
+    }
+    for x in i32::MAX - 512..=i32::MAX {
+        check_int_to_float!(x, f32);
+        check_int_to_float!(-x, f32);
+    }
+    for x in u32::MAX - 512..=u32::MAX {
+        check_int_to_float!(x, f32);
+    }
+    for x in i64::MAX - 4096..=i64::MAX {
+        check_int_to_float!(x, f32);
+        check_int_to_float!(-x, f32);
+    }
+    for x in u64::MAX - 4096..=u64::MAX {
+        check_int_to_float!(x, f32);
+    }
+    check_int_to_float!(i32::MIN, f32);
+    check_int_to_float!(i64::MIN, f32);
+    check_int_to_float!(0_i32, f32);
+}
+
+#[test]
+fn int_to_f64_sides() {
+    for x in (1_i64 << 53) - 16..(1_i64 << 53) + 64 {
+        check_int_to_float!(x, f64);
+        check_int_to_float!(-x, f64);
+    }
+    for x in i64::MAX - 4096..=i64::MAX {
+        check_int_to_float!(x, f64);
+        check_int_to_float!(-x, f64);
+    }
+    for x in u64::MAX - 4096..=u64::MAX {
+        check_int_to_float!(x, f64);
+    }
+    check_int_to_float!(i64::MIN, f64);
+    check_int_to_float!(u32::MAX, f64);
+}
+
+#[test]
+fn exact_values_are_unchanged() {
+    for rounding in MODES {
+        assert_eq!(16777216.0_f32, 16777216_u32.saturating_cast_rounded(rounding));
+        assert_eq!(-2147483648.0_f32, i32::MIN.saturating_cast_rounded(rounding));
+        assert_eq!(255.0_f32, u8::MAX.saturating_cast_rounded(rounding));
+        assert_eq!(-128.0_f64, i8::MIN.saturating_cast_rounded(rounding));
+        assert_eq!(0.0_f64, 0_usize.saturating_cast_rounded(rounding));
+    }
+}
+
+#[test]
+fn saturation_at_f32_max() {
+    let max = f32::MAX as u128;
+    assert_eq!(f32::MAX, u128::MAX.saturating_cast_rounded::<f32>(Rounding::NearestEven));
+    assert_eq!(f32::MAX, u128::MAX.saturating_cast_rounded::<f32>(Rounding::TowardZero));
+    assert_eq!(f32::MAX, u128::MAX.saturating_cast_rounded::<f32>(Rounding::TowardPositive));
+    assert_eq!(f32::MAX, u128::MAX.saturating_cast_rounded::<f32>(Rounding::TowardNegative));
+    assert_eq!(f32::MAX, (max + 1).saturating_cast_rounded::<f32>(Rounding::TowardPositive));
+    assert_eq!(f32::MAX, (max - 1).saturating_cast_rounded::<f32>(Rounding::TowardPositive));
+    assert_eq!(
+        f32::MAX.next_down(),
+        (max - 1).saturating_cast_rounded::<f32>(Rounding::TowardZero)
+    );
+
+    // 128-bit magnitudes round in the requested direction
+    let x = i128::MAX;
+    assert_eq!(2.0_f32.powi(127), x.saturating_cast_rounded::<f32>(Rounding::TowardPositive));
+    assert_eq!(
+        2.0_f32.powi(127).next_down(),
+        x.saturating_cast_rounded::<f32>(Rounding::TowardZero)
+    );
+    assert_eq!(
+        -(2.0_f64.powi(127)),
+        i128::MIN.saturating_cast_rounded::<f64>(Rounding::TowardZero)
+    );
+    assert_eq!(
+        -(2.0_f64.powi(127).next_down()),
+        (i128::MIN + 1).saturating_cast_rounded::<f64>(Rounding::TowardZero)
+    );
+    assert_eq!(
+        -(2.0_f64.powi(127)),
+        (i128::MIN + 1).saturating_cast_rounded::<f64>(Rounding::TowardNegative)
+    );
+    assert_eq!(2.0_f64.powi(128), u128::MAX.saturating_cast_rounded::<f64>(Rounding::NearestEven));
+    assert_eq!(
+        2.0_f64.powi(128).next_down(),
+        u128::MAX.saturating_cast_rounded::<f64>(Rounding::TowardNegative)
+    );
+}
+
+// Compares rounding against the std rounding functions, which don't saturate
+// but return floats that `as` then saturates.
+macro_rules! check_float_to_int {
+    ($x: expr => $($target: ty),*) => {$(
+        let x = $x;
+        let expected = [
+            (Rounding::NearestEven, x.round_ties_even()),
+            (Rounding::NearestAwayFromZero, x.round()),
+            (Rounding::TowardZero, x.trunc()),
+            (Rounding::TowardPositive, x.ceil()),
+            (Rounding::TowardNegative, x.floor()),
+        ];
+        for (rounding, rounded) in expected {
+            assert_eq!(
+                rounded as $target,
+                x.saturating_cast_rounded::<$target>(rounding),
+                "{x} {rounding:?}"
+            );
+        }
+    )*};
+}
+
+#[test]
+fn float_to_int_matches_std() {
+    for i in -2400..2400 {
+        let x = i as f32 / 8.0;
+        check_float_to_int!(x => u8, i8, i16, u32, i64);
+        check_float_to_int!(f64::from(x) => u8, i8, i16, u32, i64);
+    }
+
+    let edges_f32 = [
+        8388607.5_f32,
+        8388608.0,
+        -8388607.5,
+        16777215.0,
+        2147483520.0,
+        2147483648.0,
+        -2147483904.0,
+        f32::MAX,
+        f32::MIN,
+        f32::MIN_POSITIVE,
+        -0.0,
+        0.49999997,
+        -0.49999997,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    for x in edges_f32 {
+        check_float_to_int!(x => u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+    }
+
+    let edges_f64 = [
+        4503599627370495.5_f64,
+        -4503599627370495.5,
+        4503599627370496.0,
+        2147483647.5,
+        -2147483648.5,
+        4294967295.5,
+        -0.5,
+        0.49999999999999994,
+        f64::MAX,
+        f64::MIN,
+        f64::NAN,
+    ];
+    for x in edges_f64 {
+        check_float_to_int!(x => u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+    }
+}
+
+#[test]
+fn float_to_int_saturation() {
+    // Rounding up past the bound saturates
+    assert_eq!(255_u8, 255.5_f32.saturating_cast_rounded(Rounding::NearestEven));
+    assert_eq!(255_u8, 255.5_f32.saturating_cast_rounded(Rounding::NearestAwayFromZero));
+    assert_eq!(255_u8, 255.1_f32.saturating_cast_rounded(Rounding::TowardPositive));
+    assert_eq!(255_u8, 255.9_f32.saturating_cast_rounded(Rounding::TowardZero));
+    assert_eq!(0_u8, (-0.5_f32).saturating_cast_rounded(Rounding::NearestAwayFromZero));
+    assert_eq!(0_u8, (-0.1_f64).saturating_cast_rounded(Rounding::TowardNegative));
+    assert_eq!(i8::MIN, (-128.5_f64).saturating_cast_rounded(Rounding::NearestAwayFromZero));
+    assert_eq!(i32::MAX, 2147483647.5_f64.saturating_cast_rounded(Rounding::NearestEven));
+
+    // NaN converts to zero with every rounding mode
+    for rounding in MODES {
+        assert_eq!(0_i32, f32::NAN.saturating_cast_rounded(rounding));
+        assert_eq!(0_u64, f64::NAN.saturating_cast_rounded(rounding));
+    }
+}
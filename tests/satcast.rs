@@ -0,0 +1,116 @@
+//! Tests for the `satcast` command-line tool.
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+fn satcast(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_satcast"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn le_bytes(values: &[i32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+#[test]
+fn converts_stdin_to_stdout() {
+    let output = satcast(&["i32", "i16"], &le_bytes(&[-40000, -5, 0, 5, 40000]));
+    assert!(output.status.success());
+    let expected: Vec<u8> =
+        [i16::MIN, -5, 0, 5, i16::MAX].iter().flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(expected, output.stdout);
+    assert!(output.stderr.is_empty());
+
+    let output =
+        satcast(&["--output-endian", "big", "i32", "u16", "-", "-"], &le_bytes(&[-1, 258]));
+    assert_eq!(vec![0, 0, 1, 2], output.stdout);
+
+    let input: Vec<u8> = [1.5_f64, -1e300, f64::NAN].iter().flat_map(|x| x.to_be_bytes()).collect();
+    let output = satcast(&["-e", "big", "f64", "f32"], &input);
+    let floats: Vec<f32> =
+        output.stdout.chunks_exact(4).map(|x| f32::from_be_bytes(x.try_into().unwrap())).collect();
+    assert_eq!((1.5, f32::MIN), (floats[0], floats[1]));
+    assert!(floats[2].is_nan());
+}
+
+#[test]
+fn offset_and_stride() {
+    // A header followed by records of an `i32` sample and a 4 byte tag
+    let mut input = vec![0xaa; 3];
+    for sample in [300_i32, -300, 7] {
+        input.extend_from_slice(&sample.to_le_bytes());
+        input.extend_from_slice(b"tag!");
+    }
+    input.truncate(input.len() - 4);
+
+    let output = satcast(&["--offset", "3", "--stride", "8", "i32", "i8"], &input);
+    assert!(output.status.success());
+    assert_eq!(vec![127, 128, 7], output.stdout);
+
+    let output = satcast(&["--stride", "2", "i32", "i8"], &input);
+    assert!(!output.status.success());
+    let output = satcast(&["--offset", "100", "i32", "i8"], &input);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn clipping_report() {
+    let output = satcast(&["--report", "i32", "u8"], &le_bytes(&[-7, 0, 100, 255, 256, 1000]));
+    assert!(output.status.success());
+    assert_eq!(vec![0, 0, 100, 255, 255, 255], output.stdout);
+
+    let report = String::from_utf8(output.stderr).unwrap();
+    assert!(report.contains("elements:      6"));
+    assert!(report.contains("clipped low:   1 (16.667%) to u8::MIN"));
+    assert!(report.contains("clipped high:  2 (33.333%) to u8::MAX"));
+    assert!(report.contains("NaN to zero:   0 (0.000%)"));
+    assert!(report.contains("source min:    -7"));
+    assert!(report.contains("source max:    1000"));
+
+    let input: Vec<u8> =
+        [f32::NAN, -0.5, 1e10, -f32::NAN].iter().flat_map(|x| x.to_le_bytes()).collect();
+    let output = satcast(&["-r", "f32", "i16"], &input);
+    assert!(output.status.success());
+    assert_eq!(vec![0, 0, 0, 0, 0xff, 0x7f, 0, 0], output.stdout);
+
+    let report = String::from_utf8(output.stderr).unwrap();
+    assert!(report.contains("elements:      4"));
+    assert!(report.contains("clipped low:   0 (0.000%) to i16::MIN"));
+    assert!(report.contains("clipped high:  1 (25.000%) to i16::MAX"));
+    assert!(report.contains("NaN to zero:   2 (50.000%)"));
+    assert!(report.contains("source min:    -0.5"));
+    assert!(report.contains("source max:    10000000000"));
+}
+
+#[test]
+fn files_and_errors() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let input = dir.join("satcast_input.bin");
+    let output = dir.join("satcast_output.bin");
+    fs::write(&input, le_bytes(&[70000, -1])).unwrap();
+
+    let args = ["u32", "u16", input.to_str().unwrap(), output.to_str().unwrap()];
+    assert!(satcast(&args, &[]).status.success());
+    assert_eq!(vec![0xff, 0xff, 0xff, 0xff], fs::read(&output).unwrap());
+
+    assert!(satcast(&["--help"], &[]).status.success());
+    assert_eq!(Some(2), satcast(&["i32"], &[]).status.code());
+    assert_eq!(Some(2), satcast(&["i32", "u7"], &[]).status.code());
+    assert_eq!(Some(2), satcast(&["--bogus", "i32", "u8"], &[]).status.code());
+
+    let output = satcast(&["i32", "u8"], &[1, 0, 0, 0, 2]);
+    assert!(!output.status.success());
+    assert_eq!(vec![1], output.stdout);
+}
@@ -2,9 +2,6 @@
 
 use saturating_cast::SaturatingCast;
 
-use core::{i128, i16, i32, i64, i8, isize};
-use core::{u128, u16, u32, u64, u8, usize};
-
 #[test]
 fn i8_lossy() {
     // impl_int_clamp_to_zero_with_larger_uint_max!(i8 => u8, u16, u32, u64, u128, usize);
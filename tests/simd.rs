@@ -0,0 +1,49 @@
+//! Tests for bulk narrowing casts which use SIMD instructions.
+
+use saturating_cast::{saturating_cast_slice, simd, LengthError};
+
+#[test]
+fn matches_saturating_cast_slice() {
+    let wide: Vec<i32> = (-70_000..70_000).step_by(3).collect();
+    let mut expected = vec![0_i16; wide.len()];
+    let mut dst = vec![0_i16; wide.len()];
+    saturating_cast_slice(&wide, &mut expected).unwrap();
+    simd::i32_to_i16(&wide, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+
+    let mut expected = vec![0_u16; wide.len()];
+    let mut dst = vec![0_u16; wide.len()];
+    saturating_cast_slice(&wide, &mut expected).unwrap();
+    simd::i32_to_u16(&wide, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+
+    let signed: Vec<i16> = (i16::MIN..=i16::MAX).collect();
+    let mut expected = vec![0_u8; signed.len()];
+    let mut dst = vec![0_u8; signed.len()];
+    saturating_cast_slice(&signed, &mut expected).unwrap();
+    simd::i16_to_u8(&signed, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+
+    let mut expected = vec![0_i8; signed.len()];
+    let mut dst = vec![0_i8; signed.len()];
+    saturating_cast_slice(&signed, &mut expected).unwrap();
+    simd::i16_to_i8(&signed, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+
+    let unsigned: Vec<u16> = (u16::MIN..=u16::MAX).collect();
+    let mut expected = vec![0_u8; unsigned.len()];
+    let mut dst = vec![0_u8; unsigned.len()];
+    saturating_cast_slice(&unsigned, &mut expected).unwrap();
+    simd::u16_to_u8(&unsigned, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+}
+
+#[test]
+fn length_mismatch() {
+    let mut dst = [7_u8; 40];
+    assert_eq!(Err(LengthError::new(41, 40)), simd::u16_to_u8(&[300; 41], &mut dst));
+    assert_eq!([7; 40], dst);
+
+    let mut empty: [i16; 0] = [];
+    assert_eq!(Ok(()), simd::i32_to_i16(&[], &mut empty));
+}
@@ -0,0 +1,51 @@
+//! Tests for saturating casts between slices.
+
+use saturating_cast::{saturating_cast_slice, LengthError, SaturatingCast};
+
+#[test]
+fn matches_scalar_casts() {
+    let src: Vec<i32> = (-70000..70000).step_by(7).collect();
+
+    let mut dst = vec![0_u16; src.len()];
+    saturating_cast_slice(&src, &mut dst).unwrap();
+    assert!(src.iter().zip(&dst).all(|(s, &d)| s.saturating_cast::<u16>() == d));
+
+    let mut dst = vec![0_i8; src.len()];
+    saturating_cast_slice(&src, &mut dst).unwrap();
+    assert!(src.iter().zip(&dst).all(|(s, &d)| s.saturating_cast::<i8>() == d));
+
+    let floats = [f32::NAN, f32::NEG_INFINITY, -1.5, 0.5, 254.9, 1e10];
+    let mut bytes = [1_u8; 6];
+    saturating_cast_slice(&floats, &mut bytes).unwrap();
+    assert_eq!([0, 0, 0, 0, 254, 255], bytes);
+}
+
+#[test]
+fn length_mismatch() {
+    let src = [1_u64, 2, 3];
+
+    let mut dst = [7_u8; 4];
+    assert_eq!(Err(LengthError::new(3, 4)), saturating_cast_slice(&src, &mut dst));
+    assert_eq!([7; 4], dst);
+
+    let mut dst = [7_u8; 2];
+    let err = saturating_cast_slice(&src, &mut dst).unwrap_err();
+    assert_eq!((3, 2), (err.src_len(), err.dst_len()));
+    assert_eq!("source length 3 does not match destination length 2", err.to_string());
+
+    let mut empty: [u8; 0] = [];
+    assert_eq!(Ok(()), saturating_cast_slice::<u64, u8>(&[], &mut empty));
+}
+
+#[test]
+fn arrays_and_tuples() {
+    let pixels = [[-1_i32, 128, 300], [5, 6, 7]];
+    let mut rgb = [[0_u8; 3]; 2];
+    saturating_cast_slice(&pixels, &mut rgb).unwrap();
+    assert_eq!([[0, 128, 255], [5, 6, 7]], rgb);
+
+    let records = [(70000_u32, -1_i64)];
+    let mut narrow = [(0_u16, 0_u16)];
+    saturating_cast_slice(&records, &mut narrow).unwrap();
+    assert_eq!([(u16::MAX, 0)], narrow);
+}
@@ -0,0 +1,104 @@
+//! Tests for saturation statistics collected over many casts.
+
+use saturating_cast::{
+    saturating_cast_slice, saturating_cast_slice_stats, LengthError, SaturatingCast,
+    SaturatingCastIterator, Saturation, SaturationStats,
+};
+
+#[test]
+fn slice_stats() {
+    let src: Vec<i32> = (-1000..1000).map(|x| x * 67).collect();
+    let mut expected = vec![0_i16; src.len()];
+    let mut dst = vec![0_i16; src.len()];
+    saturating_cast_slice(&src, &mut expected).unwrap();
+    let stats = saturating_cast_slice_stats(&src, &mut dst).unwrap();
+    assert_eq!(expected, dst);
+
+    let low = src.iter().filter(|&&x| x < i32::from(i16::MIN)).count();
+    let high = src.iter().filter(|&&x| x > i32::from(i16::MAX)).count();
+    assert_eq!(src.len(), stats.count());
+    assert_eq!(low, stats.clamped_to_min());
+    assert_eq!(high, stats.clamped_to_max());
+    assert_eq!(low + high, stats.clamped());
+    assert_eq!(Some(-67000), stats.min());
+    assert_eq!(Some(66933), stats.max());
+
+    let mut short = [0_u8; 1];
+    assert_eq!(Err(LengthError::new(2, 1)), saturating_cast_slice_stats(&[1_u16, 2], &mut short));
+}
+
+#[test]
+fn merged_chunks() {
+    let src: Vec<i64> = (0..1000).map(|x| (x - 500) * x).collect();
+    let mut dst = vec![0_u8; src.len()];
+    let whole = saturating_cast_slice_stats(&src, &mut dst).unwrap();
+
+    let mut merged = SaturationStats::new();
+    for (src, dst) in src.chunks(64).zip(dst.chunks_mut(64)) {
+        merged.merge(saturating_cast_slice_stats(src, dst).unwrap());
+    }
+    assert_eq!(whole, merged);
+
+    merged.merge(SaturationStats::new());
+    assert_eq!(whole, merged);
+
+    let mut empty = SaturationStats::<i64>::default();
+    assert_eq!((0, None, None), (empty.count(), empty.min(), empty.max()));
+    empty.merge(whole);
+    assert_eq!(whole, empty);
+}
+
+#[test]
+fn iterator_stats() {
+    let values = [300_u32, 0, 70, 256, 1];
+    let mut iter = values.iter().copied().saturating_cast_stats::<u8>();
+    assert_eq!(5, iter.len());
+    assert_eq!(Some(255), iter.next());
+    assert_eq!(Some(1), iter.next_back());
+    assert_eq!(1, iter.stats().clamped_to_max());
+
+    let rest: Vec<u8> = iter.by_ref().collect();
+    assert_eq!(vec![0, 70, 255], rest);
+    assert_eq!(None, iter.next());
+
+    let stats = iter.into_stats();
+    assert_eq!((5, 0, 2), (stats.count(), stats.clamped_to_min(), stats.clamped_to_max()));
+    assert_eq!((Some(0), Some(300)), (stats.min(), stats.max()));
+
+    let expected: Vec<u8> = values.iter().map(SaturatingCast::saturating_cast).collect();
+    let adapted: Vec<u8> = values.into_iter().saturating_cast_stats::<u8>().collect();
+    assert_eq!(expected, adapted);
+}
+
+#[test]
+fn manual_records() {
+    let mut stats = SaturationStats::new();
+    stats.record(f32::NAN, Saturation::Nan);
+    assert_eq!((1, None, None), (stats.count(), stats.min(), stats.max()));
+    assert_eq!((1, 0), (stats.nan(), stats.clamped()));
+
+    stats.record(-1e9, Saturation::ClampedToMin);
+    stats.record(2.5, Saturation::Exact);
+    stats.record(f32::INFINITY, Saturation::ClampedToMax);
+    assert_eq!((4, 2), (stats.count(), stats.clamped()));
+    assert_eq!((Some(-1e9), Some(f32::INFINITY)), (stats.min(), stats.max()));
+}
+
+#[test]
+fn float_stats() {
+    let values = [f64::NAN, -1.5, 0.5, 300.0, -f64::NAN, f64::INFINITY];
+    let mut bytes = [0_u8; 6];
+    let stats = saturating_cast_slice_stats(&values, &mut bytes).unwrap();
+    assert_eq!([0, 0, 0, 255, 0, 255], bytes);
+    assert_eq!(
+        (6, 2, 1, 2),
+        (stats.count(), stats.nan(), stats.clamped_to_min(), stats.clamped_to_max())
+    );
+    assert_eq!((Some(-1.5), Some(f64::INFINITY)), (stats.min(), stats.max()));
+
+    let mut iter = values.into_iter().saturating_cast_stats::<i8>();
+    let _ = iter.by_ref().count();
+    let mut merged = iter.into_stats();
+    merged.merge(stats);
+    assert_eq!((12, 4), (merged.count(), merged.nan()));
+}
@@ -0,0 +1,129 @@
+//! Tests for saturating casts of raw binary streams.
+#![cfg(feature = "std")]
+
+use std::io::{self, Read};
+
+use saturating_cast::{
+    saturating_cast_slice, saturating_cast_slice_stats, Endian, RawElement, StreamConverter,
+};
+
+// Reader which returns at most `chunk` bytes per read and is interrupted before
+// every read.
+struct Trickle<'a> {
+    bytes: &'a [u8],
+    chunk: usize,
+    interrupt: bool,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let len = self.chunk.min(buf.len()).min(self.bytes.len());
+        buf[..len].copy_from_slice(&self.bytes[..len]);
+        self.bytes = &self.bytes[len..];
+        Ok(len)
+    }
+}
+
+fn encode<S: RawElement>(values: &[S], endian: Endian) -> Vec<u8> {
+    let mut bytes = vec![0; values.len() * S::SIZE];
+    for (value, dst) in values.iter().zip(bytes.chunks_exact_mut(S::SIZE)) {
+        value.write_bytes(dst, endian);
+    }
+    bytes
+}
+
+fn decode<T: RawElement>(bytes: &[u8], endian: Endian) -> Vec<T> {
+    bytes.chunks_exact(T::SIZE).map(|src| T::from_bytes(src, endian)).collect()
+}
+
+#[test]
+fn matches_saturating_cast_slice() {
+    let src: Vec<i32> = (-100_000..100_000).step_by(7).collect();
+    let mut expected = vec![0_i16; src.len()];
+    saturating_cast_slice(&src, &mut expected).unwrap();
+
+    for (input, output) in [(Endian::Little, Endian::Big), (Endian::Big, Endian::Little)] {
+        let bytes = encode(&src, input);
+        let mut converted = Vec::new();
+        let count = StreamConverter::new()
+            .input_endian(input)
+            .output_endian(output)
+            .convert::<i32, i16>(bytes.as_slice(), &mut converted)
+            .unwrap();
+        assert_eq!(src.len() as u64, count);
+        assert_eq!(expected, decode::<i16>(&converted, output));
+    }
+}
+
+#[test]
+fn widening_and_floats() {
+    let src: Vec<u8> = (0..=255).cycle().take(20_000).collect();
+    let mut converted = Vec::new();
+    StreamConverter::new().convert::<u8, i128>(src.as_slice(), &mut converted).unwrap();
+    let expected: Vec<i128> = src.iter().map(|&x| i128::from(x)).collect();
+    assert_eq!(expected, decode::<i128>(&converted, Endian::Little));
+
+    let floats = [f64::NAN, -1e300, -1.5, 0.5, 1e300, f64::INFINITY];
+    let bytes = encode(&floats, Endian::NATIVE);
+    let mut converted = Vec::new();
+    let converter = StreamConverter::new().input_endian(Endian::NATIVE);
+    converter.convert::<f64, u16>(bytes.as_slice(), &mut converted).unwrap();
+    assert_eq!(vec![0, 0, 0, 0, u16::MAX, u16::MAX], decode::<u16>(&converted, Endian::Little));
+}
+
+#[test]
+fn short_reads() {
+    let src: Vec<u64> = (0..5000).map(|x| x * x * 1000).collect();
+    let bytes = encode(&src, Endian::Big);
+    let mut expected = vec![0_u32; src.len()];
+    saturating_cast_slice(&src, &mut expected).unwrap();
+
+    for chunk in [1, 3, 7, 4096, 10_000] {
+        let reader = Trickle { bytes: &bytes, chunk, interrupt: false };
+        let mut converted = Vec::new();
+        let converter = StreamConverter::new().input_endian(Endian::Big);
+        converter.convert::<u64, u32>(reader, &mut converted).unwrap();
+        assert_eq!(expected, decode::<u32>(&converted, Endian::Little));
+    }
+}
+
+#[test]
+fn partial_element() {
+    let bytes = [1, 0, 0, 0, 0xff, 0xff, 0xff, 0x7f, 5, 6];
+    let mut converted = Vec::new();
+    let err = StreamConverter::new().convert::<i32, u8>(&bytes[..], &mut converted).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    assert_eq!(vec![1, 255], converted);
+
+    let mut converted = Vec::new();
+    assert_eq!(0, StreamConverter::new().convert::<i32, u8>(io::empty(), &mut converted).unwrap());
+    assert!(converted.is_empty());
+}
+
+#[test]
+fn stream_stats() {
+    let src: Vec<i32> = (-100_000..100_000).step_by(7).collect();
+    let bytes = encode(&src, Endian::Little);
+    let mut expected = vec![0_i16; src.len()];
+    let expected_stats = saturating_cast_slice_stats(&src, &mut expected).unwrap();
+
+    let mut converted = Vec::new();
+    let stats =
+        StreamConverter::new().convert_stats::<i32, i16>(bytes.as_slice(), &mut converted).unwrap();
+    assert_eq!(expected_stats, stats);
+    assert_eq!(expected, decode::<i16>(&converted, Endian::Little));
+
+    let floats = [f32::NAN, -1e9, 0.5, 255.5, 256.0, 1e9];
+    let bytes = encode(&floats, Endian::Big);
+    let mut converted = Vec::new();
+    let converter = StreamConverter::new().input_endian(Endian::Big);
+    let stats = converter.convert_stats::<f32, u8>(bytes.as_slice(), &mut converted).unwrap();
+    assert_eq!(vec![0, 0, 0, 255, 255, 255], converted);
+    assert_eq!((6, 1, 2), (stats.count(), stats.clamped_to_min(), stats.clamped_to_max()));
+    assert_eq!(1, stats.nan());
+    assert_eq!((Some(-1e9), Some(1e9)), (stats.min(), stats.max()));
+}
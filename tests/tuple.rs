@@ -0,0 +1,36 @@
+//! Tests for position-wise saturating casts of tuples.
+
+use saturating_cast::{saturate, SaturatingCast, SaturatingFrom};
+
+#[test]
+fn heterogeneous_tuples() {
+    let record = (-40000_i64, 70000_i64, 70000_u32);
+    assert_eq!((i16::MIN, i16::MAX, u16::MAX), record.saturating_cast::<(i16, i16, u16)>());
+    assert_eq!((-40000, 70000, 70000), record.saturating_cast::<(i32, u64, i128)>());
+
+    let mixed = (f32::NAN, -1_i8, u128::MAX, 2.5_f64);
+    assert_eq!((0, 0, f32::MAX, 2), mixed.saturating_cast::<(u8, u8, f32, i64)>());
+
+    assert_eq!((255_u8,), (1024_i32,).saturating_cast::<(u8,)>());
+    assert_eq!((0_u8, -1_i8), <(u8, i8)>::saturating_from((-1_i16, -1_i16)));
+}
+
+#[test]
+fn arity_twelve() {
+    let wide = (
+        -1_i32, 256_i32, 3_u64, -4_i8, 5_u8, 6_i16, 7_u16, 8_i64, 9_u32, 10.5_f32, 11_isize,
+        300_usize,
+    );
+    let narrow: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) = saturate(wide);
+    assert_eq!((0, 255, 3, 0, 5, 6, 7, 8, 9, 10, 11, 255), narrow);
+}
+
+#[test]
+fn nested_and_borrowed() {
+    let nested = ([-1_i32, 1024], (u64::MAX, -5_i64));
+    assert_eq!(([0, 255], (u32::MAX, -5)), nested.saturating_cast::<([u8; 2], (u32, i8))>());
+
+    let records = [(1_i64, -1_i64), (100_000, 7)];
+    let narrowed: Vec<(u16, u16)> = records.iter().map(saturate).collect();
+    assert_eq!(vec![(1, 0), (u16::MAX, 7)], narrowed);
+}
@@ -2,9 +2,6 @@
 
 use saturating_cast::SaturatingCast;
 
-use core::{i128, i16, i32, i64, i8, isize};
-use core::{u128, u16, u32, u64, u8, usize};
-
 #[test]
 fn u8_lossy() {
     // impl_uint_clamp_to_max_bound!(u8 => i8);
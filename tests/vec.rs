@@ -0,0 +1,60 @@
+//! Tests for saturating casts between vectors.
+#![cfg(feature = "alloc")]
+
+use saturating_cast::{saturating_cast_vec, SaturatingCast};
+
+#[test]
+fn matches_scalar_casts() {
+    let src: Vec<i64> = (-100_000..100_000).step_by(13).collect();
+
+    let dst: Vec<i16> = saturating_cast_vec(src.clone());
+    assert_eq!(src.len(), dst.len());
+    assert!(src.iter().zip(&dst).all(|(s, &d)| s.saturating_cast::<i16>() == d));
+
+    let floats = vec![f64::NAN, -1.5, 0.5, 1e300];
+    assert_eq!(vec![0, 0, 0, u32::MAX], saturating_cast_vec::<f64, u32>(floats));
+
+    assert_eq!(Vec::<u8>::new(), saturating_cast_vec::<i32, u8>(Vec::new()));
+}
+
+#[test]
+fn reuses_allocation() {
+    let src: Vec<i32> = (-5000..5000).map(|x| x * 1000).collect();
+    let (ptr, capacity) = (src.as_ptr() as usize, src.capacity());
+    let dst: Vec<u32> = saturating_cast_vec(src);
+    assert_eq!((ptr, capacity), (dst.as_ptr() as usize, dst.capacity()));
+    assert_eq!(10_000, dst.len());
+    assert_eq!(0, dst[0]);
+    assert_eq!(4_999_000, dst[9999]);
+
+    let src = vec![f64::MAX, -1.0, 2.5, f64::NAN];
+    let ptr = src.as_ptr() as usize;
+    let dst: Vec<i64> = saturating_cast_vec(src);
+    assert_eq!(ptr, dst.as_ptr() as usize);
+    assert_eq!(vec![i64::MAX, -1, 2, 0], dst);
+
+    let src = vec![[-1_i16, 300], [5, 6]];
+    let ptr = src.as_ptr() as usize;
+    let dst: Vec<[u16; 2]> = saturating_cast_vec(src);
+    assert_eq!(ptr, dst.as_ptr() as usize);
+    assert_eq!(vec![[0, 300], [5, 6]], dst);
+
+    let mut src = Vec::with_capacity(8);
+    src.extend([u64::MAX, 3]);
+    let (ptr, capacity) = (src.as_ptr() as usize, src.capacity());
+    let dst: Vec<f64> = saturating_cast_vec(src);
+    assert_eq!((ptr, capacity), (dst.as_ptr() as usize, dst.capacity()));
+    assert_eq!(vec![u64::MAX as f64, 3.0], dst);
+}
+
+#[test]
+fn different_alignment_allocates() {
+    let dst: Vec<i16> = saturating_cast_vec(vec![-40000_i32, 5, 40000]);
+    assert_eq!(vec![i16::MIN, 5, i16::MAX], dst);
+
+    let dst: Vec<f32> = saturating_cast_vec(vec![u128::MAX, 1]);
+    assert_eq!(vec![f32::MAX, 1.0], dst);
+
+    let dst: Vec<u8> = saturating_cast_vec(Vec::<i64>::new());
+    assert!(dst.is_empty());
+}